@@ -120,14 +120,14 @@
 /// not supported; see [Rust by Example](https://doc.rust-lang.org/rust-by-example/primitives/tuples.html#tuples).
 #[macro_export]
 macro_rules! verify_that {
-    // ($actual:expr, [$($expecteds:expr),+ $(,)?]) => {
-    //     $crate::assertions::internal::check_matcher(
-    //         &$actual,
-    //         $crate::matchers::elements_are![$($expecteds),+],
-    //         stringify!($actual),
-    //         $crate::internal::source_location::SourceLocation::new(file!(), line!(), column!()),
-    //     )
-    // };
+    ($actual:expr, [$($expecteds:expr),+ $(,)?]) => {
+        $crate::assertions::internal::check_matcher(
+            &$actual,
+            $crate::matchers::elements_are![$($expecteds),+],
+            stringify!($actual),
+            $crate::internal::source_location::SourceLocation::new(file!(), line!(), column!()),
+        )
+    };
     ($actual:expr, {$($expecteds:expr),+ $(,)?}) => {
         $crate::assertions::internal::check_matcher(
             &$actual,
@@ -290,15 +290,20 @@ macro_rules! fail {
         // must_use on expressions is still experimental.
         #[must_use = "The assertion result must be evaluated to affect the test result."]
         fn create_fail_result(message: String) -> $crate::Result<()> {
+            let source_location = $crate::internal::source_location::SourceLocation::new(
+                file!(),
+                line!(),
+                column!(),
+            );
             Err($crate::internal::test_outcome::TestAssertionFailure::create(format!(
                 "{}\n{}",
                 message,
-                $crate::internal::source_location::SourceLocation::new(
-                    file!(),
-                    line!(),
-                    column!(),
-                ),
-            )))
+                source_location,
+            ))
+            .with_details($crate::internal::test_outcome::FailureDetails {
+                source_location: Some(source_location),
+                ..Default::default()
+            }))
         }
         create_fail_result(format!($($message),*))
     }};
@@ -306,6 +311,52 @@ macro_rules! fail {
     () => { fail!("Test failed") };
 }
 
+/// Annotates every test assertion failure in the enclosing scope with the
+/// given formatted message and the location of the invocation.
+///
+/// This is analogous to `SCOPED_TRACE` in GoogleTest C++. It is useful to
+/// identify which iteration of a loop or which call of a helper function
+/// produced a failure:
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # fn should_fail() -> std::result::Result<(), googletest::internal::test_outcome::TestFailure> {
+/// # googletest::internal::test_outcome::TestOutcome::init_current_test_outcome();
+/// for i in 1..=3 {
+///     scoped_trace!("iteration {i}");
+///     expect_that!(i, lt(3));
+/// }
+/// # googletest::internal::test_outcome::TestOutcome::close_current_test_outcome::<&str>(Ok(()))
+/// # }
+/// # should_fail().unwrap_err();
+/// ```
+///
+/// This is output as follows:
+///
+/// ```text
+/// Value of: i
+/// Expected: is less than 3
+/// Actual: 3,
+///   which is greater than or equal to 3
+///   at ...
+/// Trace: iteration 3
+///   at ...
+/// ```
+///
+/// The trace remains active until the end of the block in which this macro is
+/// invoked. Nested traces are all output, innermost first. Traces apply to
+/// failures created on the same thread, both from non-fatal assertions such as
+/// [`expect_that!`][crate::expect_that] and from fatal assertions such as [`verify_that!`].
+#[macro_export]
+macro_rules! scoped_trace {
+    ($($message:expr),+ $(,)?) => {
+        let __googletest_scoped_trace = $crate::internal::test_outcome::ScopedTrace::new(
+            format!($($message),*),
+            $crate::internal::source_location::SourceLocation::new(file!(), line!(), column!()),
+        );
+    };
+}
+
 /// Matches the given value against the given matcher, panicking if it does not
 /// match.
 ///
@@ -406,7 +457,9 @@ macro_rules! assert_pred {
 ///
 /// This can only be invoked inside tests with the
 /// [`googletest::test`][crate::test] attribute. The assertion must
-/// occur in the same thread as that running the test itself.
+/// occur in the same thread as that running the test itself, or in a thread
+/// which inherits its context, such as one spawned with
+/// [`googletest::context::spawn`][crate::context::spawn].
 ///
 /// Invoking this macro is equivalent to using
 /// [`and_log_failure`](crate::GoogleTestSupport::and_log_failure) as follows:
@@ -462,7 +515,9 @@ macro_rules! expect_that {
 ///
 /// This can only be invoked inside tests with the
 /// [`googletest::test`][crate::test] attribute. The assertion must
-/// occur in the same thread as that running the test itself.
+/// occur in the same thread as that running the test itself, or in a thread
+/// which inherits its context, such as one spawned with
+/// [`googletest::context::spawn`][crate::context::spawn].
 ///
 /// Invoking this macro is equivalent to using
 /// [`and_log_failure`](crate::GoogleTestSupport::and_log_failure) as follows:
@@ -478,13 +533,227 @@ macro_rules! expect_pred {
     }};
 }
 
+/// Checks whether evaluating the given expression panics with a message
+/// matching the given matcher.
+///
+/// Evaluates to `Result::Ok(())` if the expression panics and the matcher
+/// matches the panic message, and to `Result::Err` with a
+/// [`TestAssertionFailure`][crate::internal::test_outcome::TestAssertionFailure]
+/// if it does not panic or the matcher does not match its message. As with
+/// [`verify_that!`], the caller must handle the result.
+///
+/// The panic message is matched as a `String`, so any matcher for strings
+/// may be used:
+///
+/// ```
+/// # use googletest::prelude::*;
+/// fn divide(dividend: u32, divisor: u32) -> u32 {
+///     dividend.checked_div(divisor).expect("attempt to divide by zero")
+/// }
+///
+/// # fn should_pass() -> Result<()> {
+/// verify_panics!(divide(1, 0), contains_substring("divide by zero"))?; // Passes
+/// #     Ok(())
+/// # }
+/// # fn should_fail() -> Result<()> {
+/// verify_panics!(divide(1, 1), anything())?; // Fails: does not panic
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail().unwrap_err();
+/// ```
+///
+/// The failure message reports whether the expression panicked and, if so,
+/// why its message does not match:
+///
+/// ```text
+/// Value of: divide(1, 1)
+/// Expected: panics with a message which contains a substring "divide by zero"
+/// Actual: no panic,
+///   which did not panic
+///   at ...
+/// ```
+///
+/// The expression is evaluated in a closure, so it cannot use the question
+/// mark operator or return from the enclosing function. A panic whose payload
+/// is neither a `&str` nor a `String`, such as one raised with
+/// [`std::panic::panic_any`], never matches.
+///
+/// This catches the panic with [`std::panic::catch_unwind`], so it does not
+/// work if the test binary is compiled with `panic = "abort"`. The panic hook
+/// still runs, so the panic message is output as usual.
+#[macro_export]
+macro_rules! verify_panics {
+    ($actual:expr, $expected:expr $(,)?) => {
+        $crate::assertions::internal::check_panic(
+            || {
+                let _ = &$actual;
+            },
+            $expected,
+            stringify!($actual),
+            $crate::internal::source_location::SourceLocation::new(file!(), line!(), column!()),
+        )
+    };
+}
+
+/// Checks whether evaluating the given expression panics with a message
+/// matching the given matcher, marking the test as failed but continuing
+/// execution if it does not.
+///
+/// This is a *non-fatal* assertion: the test continues execution in the event
+/// of assertion failure. See [`verify_panics!`] for details.
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # /* The attribute macro would prevent the function from being compiled in a doctest.
+/// #[googletest::test]
+/// # */
+/// fn should_reject_empty_input() {
+/// #   googletest::internal::test_outcome::TestOutcome::init_current_test_outcome();
+///     expect_panic!(Vec::<u32>::new()[0], contains_substring("out of bounds"));
+/// #   googletest::internal::test_outcome::TestOutcome::close_current_test_outcome::<&str>(Ok(()))
+/// #       .unwrap();
+/// }
+/// # should_reject_empty_input();
+/// ```
+///
+/// This can only be invoked inside tests with the
+/// [`googletest::test`][crate::test] attribute. Invoking this macro is
+/// equivalent to using
+/// [`and_log_failure`](crate::GoogleTestSupport::and_log_failure) as follows:
+///
+/// ```ignore
+/// verify_panics!(actual, expected).and_log_failure()
+/// ```
+#[macro_export]
+macro_rules! expect_panic {
+    ($actual:expr, $expected:expr $(,)?) => {{
+        use $crate::GoogleTestSupport;
+        $crate::verify_panics!($actual, $expected).and_log_failure();
+    }};
+}
+
+/// Checks whether running the given closure in a child process terminates the
+/// process with an exit status and output to stderr matching the given
+/// matchers.
+///
+/// This is a *death test*, analogous to `EXPECT_EXIT` in GoogleTest C++. It
+/// evaluates to `Result::Ok(())` if the closure terminates the child process,
+/// the matcher for the [`ExitStatus`][std::process::ExitStatus] matches its
+/// exit status, and the matcher for the `String`, if given, matches its output
+/// to stderr. Otherwise it evaluates to `Result::Err` with a
+/// [`TestAssertionFailure`][crate::internal::test_outcome::TestAssertionFailure].
+/// As with [`verify_that!`], the caller must handle the result.
+///
+/// Use [`exited_with_code`][crate::death_test::exited_with_code] or
+/// [`killed_by_signal`][crate::death_test::killed_by_signal] to match the exit
+/// status:
+///
+/// ```ignore
+/// #[test]
+/// fn should_exit_on_invalid_configuration() -> Result<()> {
+///     verify_exit!(
+///         || load_configuration("invalid"),
+///         exited_with_code(eq(2)),
+///         contains_substring("invalid configuration")
+///     )
+/// }
+/// ```
+///
+/// The matcher for stderr may be omitted to check only the exit status:
+///
+/// ```ignore
+/// verify_exit!(|| load_configuration("invalid"), exited_with_code(eq(2)))
+/// ```
+///
+/// If the closure returns without terminating the process, the assertion
+/// fails:
+///
+/// ```text
+/// Value of: || load_configuration("valid")
+/// Expected: exits with a code which is equal to 2, with stderr which contains a substring "invalid configuration"
+/// Actual: returned with stderr "",
+///   which did not exit
+///   at ...
+/// ```
+///
+/// The child process runs the test binary again, filtered to the test
+/// containing the assertion. See [`death_test`][crate::death_test] for the
+/// restrictions which this entails.
+///
+/// This is only available on Linux.
+#[cfg(target_os = "linux")]
+#[macro_export]
+macro_rules! verify_exit {
+    ($action:expr, $status_matcher:expr $(,)?) => {
+        $crate::death_test::internal::check_exit_status(
+            $action,
+            $status_matcher,
+            stringify!($action),
+            $crate::internal::source_location::SourceLocation::new(file!(), line!(), column!()),
+        )
+    };
+    ($action:expr, $status_matcher:expr, $stderr_matcher:expr $(,)?) => {
+        $crate::death_test::internal::check_exit(
+            $action,
+            $status_matcher,
+            $stderr_matcher,
+            stringify!($action),
+            $crate::internal::source_location::SourceLocation::new(file!(), line!(), column!()),
+        )
+    };
+}
+
+/// Checks whether running the given closure in a child process terminates the
+/// process with an exit status and output to stderr matching the given
+/// matchers, marking the test as failed but continuing execution if not.
+///
+/// This is a *non-fatal* death test assertion: the test continues execution
+/// in the event of assertion failure. See [`verify_exit!`] for details.
+///
+/// ```ignore
+/// #[googletest::test]
+/// fn should_abort_on_corrupt_input() {
+///     expect_exit!(|| std::process::abort(), killed_by_signal(eq(6)));
+/// }
+/// ```
+///
+/// This can only be invoked inside tests with the
+/// [`googletest::test`][crate::test] attribute. Invoking this macro is
+/// equivalent to using
+/// [`and_log_failure`](crate::GoogleTestSupport::and_log_failure) as follows:
+///
+/// ```ignore
+/// verify_exit!(action, status_matcher, stderr_matcher).and_log_failure()
+/// ```
+///
+/// As with [`verify_exit!`], the matcher for stderr may be omitted.
+///
+/// This is only available on Linux.
+#[cfg(target_os = "linux")]
+#[macro_export]
+macro_rules! expect_exit {
+    ($action:expr, $status_matcher:expr $(,)?) => {{
+        use $crate::GoogleTestSupport;
+        $crate::verify_exit!($action, $status_matcher).and_log_failure();
+    }};
+    ($action:expr, $status_matcher:expr, $stderr_matcher:expr $(,)?) => {{
+        use $crate::GoogleTestSupport;
+        $crate::verify_exit!($action, $status_matcher, $stderr_matcher).and_log_failure();
+    }};
+}
+
 /// Functions for use only by the procedural macros in this module.
 ///
 /// **For internal use only. API stablility is not guaranteed!**
 #[doc(hidden)]
 pub mod internal {
     use crate::{
-        internal::{source_location::SourceLocation, test_outcome::TestAssertionFailure},
+        internal::{
+            panic_outcome::{PanicOutcome, PanicsWithMatcher},
+            source_location::SourceLocation,
+            test_outcome::{FailureDetails, TestAssertionFailure},
+        },
         matcher::{create_assertion_failure, Matcher, MatcherResult},
     };
     use std::fmt::Debug;
@@ -511,6 +780,27 @@ pub mod internal {
         }
     }
 
+    /// Checks whether `action` panics with a message which the matcher
+    /// `expected` matches, adding a test failure report if it does not.
+    ///
+    /// This intended only for use by the macro [`crate::verify_panics`].
+    ///
+    /// **For internal use only. API stablility is not guaranteed!**
+    #[must_use = "The assertion result must be evaluated to affect the test result."]
+    pub fn check_panic(
+        action: impl FnOnce(),
+        expected: impl Matcher<ActualT = String>,
+        actual_expr: &'static str,
+        source_location: SourceLocation,
+    ) -> Result<(), TestAssertionFailure> {
+        check_matcher(
+            &PanicOutcome::of(action),
+            PanicsWithMatcher::new(expected),
+            actual_expr,
+            source_location,
+        )
+    }
+
     /// Constructs a `Result::Err(TestAssertionFailure)` for a predicate failure
     /// as produced by the macro [`crate::verify_pred`].
     ///
@@ -528,6 +818,11 @@ pub mod internal {
             actual_expr,
             formatted_arguments.join(",\n  "),
             source_location,
-        )))
+        ))
+        .with_details(FailureDetails {
+            actual_expression: Some(actual_expr),
+            source_location: Some(source_location),
+            ..Default::default()
+        }))
     }
 }
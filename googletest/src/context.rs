@@ -0,0 +1,194 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Sharing the context of the running test with other threads and futures.
+//!
+//! Non-fatal assertions such as [`expect_that!`][crate::expect_that] record
+//! failures in the context of the running test, which is only available on
+//! the thread running the test itself. Threads spawned with [`spawn`] or
+//! [`spawn_scoped`] inherit that context, so that failures recorded in them
+//! fail the test:
+//!
+//! ```
+//! # use googletest::prelude::*;
+//! # /* The attribute macro would prevent the function from being compiled in a doctest.
+//! #[googletest::test]
+//! # */
+//! fn should_check_values_in_parallel() {
+//! #   googletest::internal::test_outcome::TestOutcome::init_current_test_outcome();
+//!     std::thread::scope(|scope| {
+//!         for value in [1, 2, 3] {
+//!             googletest::context::spawn_scoped(scope, move || {
+//!                 expect_that!(value, gt(0));
+//!             });
+//!         }
+//!     });
+//! #   googletest::internal::test_outcome::TestOutcome::close_current_test_outcome::<&str>(Ok(()))
+//! #       .unwrap();
+//! }
+//! # should_check_values_in_parallel();
+//! ```
+//!
+//! Failures recorded outside of the thread running the test are output with
+//! the name of the thread in which they occurred.
+//!
+//! The spawned threads must finish before the test does. Failures recorded
+//! after the test has finished are output but do not affect its result.
+//!
+//! Similarly, a future wrapped with [`TestContext::wrap_future`] runs in the
+//! context of the test from which the [`TestContext`] was obtained, on
+//! whichever thread it is polled. This allows tasks spawned on a
+//! multi-threaded executor to use non-fatal assertions:
+//!
+//! ```ignore
+//! #[googletest::test]
+//! #[tokio::test(flavor = "multi_thread")]
+//! async fn should_check_value_in_task() {
+//!     let context = TestContext::current();
+//!     tokio::spawn(context.wrap_future(async { expect_that!(1, eq(1)) })).await.unwrap();
+//! }
+//! ```
+//!
+//! The body of an `async` test annotated with
+//! [`googletest::test`][crate::test] is itself wrapped in this way, so that it
+//! keeps its context even if the executor moves it between threads.
+
+use crate::internal::test_outcome::TestOutcome;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use std::thread::{JoinHandle, Scope, ScopedJoinHandle};
+
+/// A handle to the context of the running test, which can be sent to other
+/// threads.
+///
+/// Use [`TestContext::run`] to run code on another thread in this context.
+/// The functions [`spawn`] and [`spawn_scoped`] do this automatically. One can
+/// also use it directly, for example to spawn a named thread:
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # use googletest::context::TestContext;
+/// # googletest::internal::test_outcome::TestOutcome::init_current_test_outcome();
+/// let context = TestContext::current();
+/// std::thread::Builder::new()
+///     .name("worker".into())
+///     .spawn(move || context.run(|| expect_that!(1, eq(1))))
+///     .unwrap()
+///     .join()
+///     .unwrap();
+/// # googletest::internal::test_outcome::TestOutcome::close_current_test_outcome::<&str>(Ok(()))
+/// #     .unwrap();
+/// ```
+#[derive(Clone)]
+pub struct TestContext {
+    outcome: Arc<TestOutcome>,
+}
+
+impl TestContext {
+    /// Returns the context of the test running on the current thread.
+    ///
+    /// Panics if no test is running on the current thread, i.e., if the
+    /// calling code does not run in a test annotated with
+    /// [`googletest::test`][crate::test] or in a [`TestContext`].
+    pub fn current() -> Self {
+        TestOutcome::ensure_text_context_present();
+        Self { outcome: TestOutcome::current().unwrap() }
+    }
+
+    /// Runs `action` on the current thread in this context.
+    ///
+    /// Assertion failures recorded by `action` are recorded as failures of the
+    /// test from which this context was obtained.
+    pub fn run<T>(&self, action: impl FnOnce() -> T) -> T {
+        TestOutcome::with_outcome_in(&mut Some(self.outcome.clone()), action)
+    }
+
+    /// Wraps `future` so that it runs in this context whenever it is polled,
+    /// regardless of the thread polling it.
+    ///
+    /// Assertion failures recorded by `future` are recorded as failures of the
+    /// test from which this context was obtained.
+    pub fn wrap_future<F: Future>(&self, future: F) -> WithTestContext<F> {
+        WithTestContext { future: Box::pin(future), outcome: Some(self.outcome.clone()) }
+    }
+}
+
+/// A future which runs in a test context whenever it is polled.
+///
+/// This is created by [`TestContext::wrap_future`].
+pub struct WithTestContext<F> {
+    future: Pin<Box<F>>,
+    outcome: Option<Arc<TestOutcome>>,
+}
+
+impl<F: Future> WithTestContext<F> {
+    /// Wraps `future` so that it runs in its own test context, initially
+    /// empty, whenever it is polled.
+    ///
+    /// This is intended only for use by the attribute macro
+    /// `#[googletest::test]`, which initialises and closes the test context
+    /// inside `future`.
+    ///
+    /// **For internal use only. API stablility is not guaranteed!**
+    #[doc(hidden)]
+    pub fn isolated(future: F) -> Self {
+        Self { future: Box::pin(future), outcome: None }
+    }
+}
+
+impl<F: Future> Future for WithTestContext<F> {
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let Self { future, outcome } = self.get_mut();
+        TestOutcome::with_outcome_in(outcome, || future.as_mut().poll(cx))
+    }
+}
+
+/// Spawns a new thread running `action` in the context of the current test.
+///
+/// This is equivalent to [`std::thread::spawn`] except that assertion
+/// failures recorded by `action` are recorded as failures of the current
+/// test.
+///
+/// Panics if no test is running on the current thread.
+pub fn spawn<F, T>(action: F) -> JoinHandle<T>
+where
+    F: FnOnce() -> T + Send + 'static,
+    T: Send + 'static,
+{
+    let context = TestContext::current();
+    std::thread::spawn(move || context.run(action))
+}
+
+/// Spawns a new scoped thread in `scope` running `action` in the context of
+/// the current test.
+///
+/// This is equivalent to [`Scope::spawn`] except that assertion failures
+/// recorded by `action` are recorded as failures of the current test.
+///
+/// Panics if no test is running on the current thread.
+pub fn spawn_scoped<'scope, 'env, F, T>(
+    scope: &'scope Scope<'scope, 'env>,
+    action: F,
+) -> ScopedJoinHandle<'scope, T>
+where
+    F: FnOnce() -> T + Send + 'scope,
+    T: Send + 'scope,
+{
+    let context = TestContext::current();
+    scope.spawn(move || context.run(action))
+}
@@ -0,0 +1,525 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Death tests, which check that code terminates the process, analogous to
+//! `EXPECT_EXIT` in GoogleTest C++.
+//!
+//! Code which calls [`std::process::exit`] or [`std::process::abort`], or
+//! which is killed by a signal, cannot be tested in the process running the
+//! test. The macros [`verify_exit!`][crate::verify_exit] and
+//! [`expect_exit!`][crate::expect_exit] therefore run a closure in a child
+//! process and match its exit status and, optionally, the output it writes to
+//! stderr:
+//!
+//! ```ignore
+//! #[googletest::test]
+//! fn should_abort_on_corrupt_input() {
+//!     expect_exit!(
+//!         || parse_or_abort("corrupt"),
+//!         killed_by_signal(eq(libc::SIGABRT)),
+//!         contains_substring("corrupt input")
+//!     );
+//! }
+//! ```
+//!
+//! The child process is the test binary itself, run again with a filter which
+//! selects only the test containing the assertion, including it even if it is
+//! marked `#[ignore]`. The child runs the test from the start up to the
+//! assertion, runs the closure, and reports to the parent if the closure
+//! returns. Other death test assertions in the same test are skipped in the
+//! child. The child identifies the assertion by its source location and by how
+//! many death test assertions the test ran before it, so an assertion which
+//! runs several times, for example in a loop, runs the right closure each
+//! time. Consequently:
+//!
+//!  * The assertion must run on the thread running the test itself, whose
+//!    name libtest sets to the name of the test.
+//!  * The test must deterministically reach the assertion, after running the
+//!    same death test assertions as in the parent.
+//!  * Side effects of the test before the assertion happen twice, once in each
+//!    process, and side effects of the closure are not visible in the parent.
+//!
+//! A panic in the closure counts as an exit of the child process, whose exit
+//! status is then that which libtest uses for a failed test, and whose stderr
+//! contains the panic message.
+//!
+//! This is only available on Linux.
+
+use crate::{
+    description::Description,
+    matcher::{Matcher, MatcherResult},
+};
+use std::fmt::{Debug, Formatter};
+use std::os::unix::process::ExitStatusExt;
+use std::process::ExitStatus;
+
+/// Matches an [`ExitStatus`] of a process which exited normally with an exit
+/// code matched by `inner`.
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # use std::os::unix::process::ExitStatusExt;
+/// # use std::process::ExitStatus;
+/// # fn should_pass() -> Result<()> {
+/// verify_that!(ExitStatus::from_raw(3 << 8), exited_with_code(eq(3)))?;  // Passes
+/// #     Ok(())
+/// # }
+/// # fn should_fail() -> Result<()> {
+/// verify_that!(ExitStatus::from_raw(6), exited_with_code(anything()))?;  // Fails: killed by signal
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail().unwrap_err();
+/// ```
+pub fn exited_with_code(inner: impl Matcher<ActualT = i32>) -> impl Matcher<ActualT = ExitStatus> {
+    ExitStatusMatcher { inner, kind: ExitKind::Code }
+}
+
+/// Matches an [`ExitStatus`] of a process which was killed by a signal whose
+/// number is matched by `inner`.
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # use std::os::unix::process::ExitStatusExt;
+/// # use std::process::ExitStatus;
+/// # fn should_pass() -> Result<()> {
+/// verify_that!(ExitStatus::from_raw(6), killed_by_signal(eq(6)))?;  // Passes
+/// #     Ok(())
+/// # }
+/// # fn should_fail() -> Result<()> {
+/// verify_that!(ExitStatus::from_raw(0), killed_by_signal(anything()))?;  // Fails: exited normally
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail().unwrap_err();
+/// ```
+pub fn killed_by_signal(inner: impl Matcher<ActualT = i32>) -> impl Matcher<ActualT = ExitStatus> {
+    ExitStatusMatcher { inner, kind: ExitKind::Signal }
+}
+
+#[derive(Clone, Copy)]
+enum ExitKind {
+    Code,
+    Signal,
+}
+
+impl ExitKind {
+    fn of(self, status: &ExitStatus) -> Option<i32> {
+        match self {
+            ExitKind::Code => status.code(),
+            ExitKind::Signal => status.signal(),
+        }
+    }
+
+    fn description(self, matcher_result: MatcherResult) -> &'static str {
+        match (self, matcher_result) {
+            (ExitKind::Code, MatcherResult::Match) => "exits with a code",
+            (ExitKind::Code, MatcherResult::NoMatch) => "doesn't exit with a code",
+            (ExitKind::Signal, MatcherResult::Match) => "is killed by a signal",
+            (ExitKind::Signal, MatcherResult::NoMatch) => "isn't killed by a signal",
+        }
+    }
+}
+
+/// Describes how a process with the exit status `status` terminated.
+fn describe_status(status: &ExitStatus) -> String {
+    match (status.code(), status.signal()) {
+        (Some(code), _) => format!("exited with code {code}"),
+        (None, Some(signal)) => format!("was killed by signal {signal}"),
+        (None, None) => status.to_string(),
+    }
+}
+
+struct ExitStatusMatcher<InnerMatcherT> {
+    inner: InnerMatcherT,
+    kind: ExitKind,
+}
+
+impl<InnerMatcherT: Matcher<ActualT = i32>> Matcher for ExitStatusMatcher<InnerMatcherT> {
+    type ActualT = ExitStatus;
+
+    fn matches(&self, actual: &ExitStatus) -> MatcherResult {
+        self.kind
+            .of(actual)
+            .map(|value| self.inner.matches(&value))
+            .unwrap_or(MatcherResult::NoMatch)
+    }
+
+    fn explain_match(&self, actual: &ExitStatus) -> Description {
+        match self.kind.of(actual) {
+            Some(value) => Description::new()
+                .text(format!("which {}", describe_status(actual)))
+                .nested(self.inner.explain_match(&value)),
+            None => format!("which {}", describe_status(actual)).into(),
+        }
+    }
+
+    fn describe(&self, matcher_result: MatcherResult) -> Description {
+        format!(
+            "{} which {}",
+            self.kind.description(matcher_result),
+            self.inner.describe(MatcherResult::Match)
+        )
+        .into()
+    }
+}
+
+/// Functions for use only by the macros [`verify_exit!`][crate::verify_exit]
+/// and [`expect_exit!`][crate::expect_exit].
+///
+/// **For internal use only. API stablility is not guaranteed!**
+#[doc(hidden)]
+pub mod internal {
+    use super::{ChildOutcome, ExitMatcher};
+    use crate::{
+        internal::{source_location::SourceLocation, test_outcome::TestAssertionFailure},
+        matcher::Matcher,
+        matchers::anything,
+    };
+    use std::cell::Cell;
+    use std::process::{Command, ExitStatus, Stdio};
+
+    /// The environment variable which tells a child process which death test
+    /// assertion to run.
+    const DEATH_TEST_ENV: &str = "GOOGLETEST_INTERNAL_DEATH_TEST";
+    /// The line which a child process writes to stdout when it has reached the
+    /// death test assertion.
+    const STARTED_MARKER: &str = "[googletest death test started]";
+    /// The line which a child process writes to stdout if the closure of the
+    /// death test returns.
+    const RETURNED_MARKER: &str = "[googletest death test returned]";
+    /// The environment variables of the reporters, which the child process must
+    /// not write to.
+    const REPORTER_ENVS: [&str; 3] =
+        ["GOOGLETEST_XML_OUTPUT_FILE", "XML_OUTPUT_FILE", "GOOGLETEST_JSON_OUTPUT_FILE"];
+
+    thread_local! {
+        /// The number of death test assertions which the test running on this
+        /// thread has run so far.
+        static DEATH_TEST_COUNT: Cell<usize> = const { Cell::new(0) };
+    }
+
+    /// Checks whether `action`, run in a child process, terminates the process
+    /// with an exit status matching `status_matcher` after writing output to
+    /// stderr matching `stderr_matcher`.
+    ///
+    /// In the child process itself, this runs `action` if this is the
+    /// assertion which the child process is to run, and otherwise does
+    /// nothing.
+    ///
+    /// **For internal use only. API stablility is not guaranteed!**
+    #[must_use = "The assertion result must be evaluated to affect the test result."]
+    pub fn check_exit<T>(
+        action: impl FnOnce() -> T,
+        status_matcher: impl Matcher<ActualT = ExitStatus>,
+        stderr_matcher: impl Matcher<ActualT = String>,
+        actual_expr: &'static str,
+        source_location: SourceLocation,
+    ) -> Result<(), TestAssertionFailure> {
+        check_child(
+            action,
+            ExitMatcher::new(status_matcher, Some(stderr_matcher)),
+            actual_expr,
+            source_location,
+        )
+    }
+
+    /// Checks whether `action`, run in a child process, terminates the process
+    /// with an exit status matching `status_matcher`, regardless of its output
+    /// to stderr.
+    ///
+    /// **For internal use only. API stablility is not guaranteed!**
+    #[must_use = "The assertion result must be evaluated to affect the test result."]
+    pub fn check_exit_status<T>(
+        action: impl FnOnce() -> T,
+        status_matcher: impl Matcher<ActualT = ExitStatus>,
+        actual_expr: &'static str,
+        source_location: SourceLocation,
+    ) -> Result<(), TestAssertionFailure> {
+        // The stderr matcher is absent; `anything` only gives it a type.
+        let stderr_matcher = false.then(anything::<String>);
+        check_child(
+            action,
+            ExitMatcher::new(status_matcher, stderr_matcher),
+            actual_expr,
+            source_location,
+        )
+    }
+
+    fn check_child<T>(
+        action: impl FnOnce() -> T,
+        matcher: impl Matcher<ActualT = ChildOutcome>,
+        actual_expr: &'static str,
+        source_location: SourceLocation,
+    ) -> Result<(), TestAssertionFailure> {
+        let index = DEATH_TEST_COUNT.with(|count| count.replace(count.get() + 1));
+        let id = format!(
+            "{}:{}:{}#{index}",
+            source_location.file(),
+            source_location.line(),
+            source_location.column()
+        );
+        match std::env::var(DEATH_TEST_ENV) {
+            Ok(child_id) if child_id == id => run_in_child(action),
+            Ok(_) => Ok(()),
+            Err(_) => crate::assertions::internal::check_matcher(
+                &run_child(&id),
+                matcher,
+                actual_expr,
+                source_location,
+            ),
+        }
+    }
+
+    fn run_in_child<T>(action: impl FnOnce() -> T) -> ! {
+        println!("\n{STARTED_MARKER}");
+        action();
+        println!("\n{RETURNED_MARKER}");
+        std::process::exit(0)
+    }
+
+    fn run_child(id: &str) -> ChildOutcome {
+        // libtest runs each test in a thread named after the test.
+        let Some(test_name) = std::thread::current().name().map(String::from) else {
+            return ChildOutcome::FailedToRun("the current thread has no test name".into());
+        };
+        let executable = match std::env::current_exe() {
+            Ok(executable) => executable,
+            Err(error) => return ChildOutcome::FailedToRun(error.to_string()),
+        };
+        let mut command = Command::new(executable);
+        // The test is run even if it is ignored, since the parent process only
+        // runs an ignored test if asked to.
+        command
+            .args([test_name.as_str(), "--exact", "--include-ignored", "--nocapture"])
+            .env(DEATH_TEST_ENV, id)
+            .stdin(Stdio::null());
+        for env in REPORTER_ENVS {
+            command.env_remove(env);
+        }
+        let output = match command.output() {
+            Ok(output) => output,
+            Err(error) => return ChildOutcome::FailedToRun(error.to_string()),
+        };
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let stderr = String::from_utf8_lossy(&output.stderr).into_owned();
+        if stdout.lines().any(|line| line == RETURNED_MARKER) {
+            ChildOutcome::DidNotExit { stderr }
+        } else if stdout.lines().any(|line| line == STARTED_MARKER) {
+            ChildOutcome::Exited { status: output.status, stderr }
+        } else {
+            ChildOutcome::FailedToRun(format!(
+                "the test {test_name} did not reach the assertion in the child process"
+            ))
+        }
+    }
+}
+
+/// What happened when running the closure of a death test in a child process.
+enum ChildOutcome {
+    Exited {
+        status: ExitStatus,
+        stderr: String,
+    },
+    /// The closure returned without terminating the child process.
+    DidNotExit {
+        stderr: String,
+    },
+    /// The child process could not run the closure for the given reason.
+    FailedToRun(String),
+}
+
+impl Debug for ChildOutcome {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ChildOutcome::Exited { status, stderr } => {
+                write!(f, "{} with stderr {stderr:?}", describe_status(status))
+            }
+            ChildOutcome::DidNotExit { stderr } => write!(f, "returned with stderr {stderr:?}"),
+            ChildOutcome::FailedToRun(reason) => write!(f, "no child process: {reason}"),
+        }
+    }
+}
+
+/// A matcher which matches a [`ChildOutcome`] in which the child process
+/// exited with a status matching `status_matcher` and, if present, stderr
+/// matching `stderr_matcher`.
+struct ExitMatcher<StatusMatcherT, StderrMatcherT> {
+    status_matcher: StatusMatcherT,
+    stderr_matcher: Option<StderrMatcherT>,
+}
+
+impl<StatusMatcherT, StderrMatcherT> ExitMatcher<StatusMatcherT, StderrMatcherT> {
+    fn new(status_matcher: StatusMatcherT, stderr_matcher: Option<StderrMatcherT>) -> Self {
+        Self { status_matcher, stderr_matcher }
+    }
+}
+
+impl<StatusMatcherT: Matcher<ActualT = ExitStatus>, StderrMatcherT: Matcher<ActualT = String>>
+    Matcher for ExitMatcher<StatusMatcherT, StderrMatcherT>
+{
+    type ActualT = ChildOutcome;
+
+    fn matches(&self, actual: &ChildOutcome) -> MatcherResult {
+        match actual {
+            ChildOutcome::Exited { status, stderr } => {
+                (self.status_matcher.matches(status).is_match()
+                    && self
+                        .stderr_matcher
+                        .as_ref()
+                        .map_or(true, |stderr_matcher| stderr_matcher.matches(stderr).is_match()))
+                .into()
+            }
+            ChildOutcome::DidNotExit { .. } | ChildOutcome::FailedToRun(_) => {
+                MatcherResult::NoMatch
+            }
+        }
+    }
+
+    fn explain_match(&self, actual: &ChildOutcome) -> Description {
+        match actual {
+            ChildOutcome::Exited { status, stderr } => {
+                let description = Description::new()
+                    .text("whose exit status")
+                    .nested(self.status_matcher.explain_match(status));
+                match &self.stderr_matcher {
+                    Some(stderr_matcher) => description
+                        .text("and whose stderr")
+                        .nested(stderr_matcher.explain_match(stderr)),
+                    None => description,
+                }
+            }
+            ChildOutcome::DidNotExit { .. } => "which did not exit".into(),
+            ChildOutcome::FailedToRun(_) => "which could not be run in a child process".into(),
+        }
+    }
+
+    fn describe(&self, matcher_result: MatcherResult) -> Description {
+        let status = self.status_matcher.describe(matcher_result);
+        match (matcher_result, &self.stderr_matcher) {
+            (MatcherResult::Match, Some(stderr_matcher)) => format!(
+                "{status}, with stderr which {}",
+                stderr_matcher.describe(MatcherResult::Match)
+            )
+            .into(),
+            (MatcherResult::Match, None) => status,
+            (MatcherResult::NoMatch, Some(stderr_matcher)) => format!(
+                "doesn't terminate the process, or {status}, or has stderr which {}",
+                stderr_matcher.describe(MatcherResult::NoMatch)
+            )
+            .into(),
+            (MatcherResult::NoMatch, None) => {
+                format!("doesn't terminate the process, or {status}").into()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{exited_with_code, killed_by_signal, ChildOutcome, ExitMatcher};
+    use crate::matcher::{Matcher, MatcherResult};
+    use crate::prelude::*;
+    use indoc::indoc;
+    use std::os::unix::process::ExitStatusExt;
+    use std::process::ExitStatus;
+
+    #[test]
+    fn exited_with_code_matches_exit_code() -> Result<()> {
+        verify_that!(ExitStatus::from_raw(3 << 8), exited_with_code(eq(3)))
+    }
+
+    #[test]
+    fn exited_with_code_does_not_match_signal() -> Result<()> {
+        let result = exited_with_code(anything()).matches(&ExitStatus::from_raw(6));
+
+        verify_that!(result, eq(MatcherResult::NoMatch))
+    }
+
+    #[test]
+    fn killed_by_signal_matches_signal() -> Result<()> {
+        verify_that!(ExitStatus::from_raw(6), killed_by_signal(eq(6)))
+    }
+
+    #[test]
+    fn killed_by_signal_explains_exit_code() -> Result<()> {
+        verify_that!(
+            killed_by_signal(eq(6)).explain_match(&ExitStatus::from_raw(1 << 8)),
+            displays_as(eq("which exited with code 1"))
+        )
+    }
+
+    #[test]
+    fn exit_matcher_matches_exit_with_matching_stderr() -> Result<()> {
+        let matcher = ExitMatcher::new(exited_with_code(eq(1)), Some(contains_substring("error")));
+
+        let result = matcher.matches(&ChildOutcome::Exited {
+            status: ExitStatus::from_raw(1 << 8),
+            stderr: "An error".into(),
+        });
+
+        verify_that!(result, eq(MatcherResult::Match))
+    }
+
+    #[test]
+    fn exit_matcher_does_not_match_when_no_exit() -> Result<()> {
+        let matcher = ExitMatcher::new(anything(), Some(anything()));
+
+        let result = matcher.matches(&ChildOutcome::DidNotExit { stderr: "".into() });
+
+        verify_that!(result, eq(MatcherResult::NoMatch))
+    }
+
+    #[test]
+    fn exit_matcher_explains_mismatch() -> Result<()> {
+        let matcher = ExitMatcher::new(exited_with_code(eq(1)), Some(contains_substring("error")));
+
+        verify_that!(
+            matcher.explain_match(&ChildOutcome::Exited {
+                status: ExitStatus::from_raw(2 << 8),
+                stderr: "A warning".into(),
+            }),
+            displays_as(eq(indoc!(
+                "
+                whose exit status
+                  which exited with code 2
+                    which isn't equal to 1
+                and whose stderr
+                  which does not contain a substring \"error\""
+            )))
+        )
+    }
+
+    #[test]
+    fn exit_matcher_describes_status_and_stderr() -> Result<()> {
+        let matcher = ExitMatcher::new(exited_with_code(eq(2)), Some(contains_substring("error")));
+
+        verify_that!(
+            matcher.describe(MatcherResult::Match),
+            displays_as(eq(
+                "exits with a code which is equal to 2, with stderr which contains a substring \"error\""
+            ))
+        )
+    }
+
+    #[test]
+    fn exit_matcher_without_stderr_matcher_describes_only_status() -> Result<()> {
+        let matcher = ExitMatcher::new(killed_by_signal(eq(6)), false.then(anything::<String>));
+
+        verify_that!(
+            matcher.describe(MatcherResult::Match),
+            displays_as(eq("is killed by a signal which is equal to 6"))
+        )
+    }
+}
@@ -0,0 +1,64 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Test fixtures with set-up and tear-down, analogous to `TEST_F` in
+//! GoogleTest C++.
+
+/// A resource which is set up before a test runs and torn down after it
+/// finishes.
+///
+/// A test annotated with [`googletest::test`][crate::test] may take any number
+/// of parameters of the form `name: &mut F` or `name: &F`, where `F`
+/// implements `Fixture`:
+///
+/// ```
+/// # use googletest::prelude::*;
+/// struct DbFixture {
+///     rows: Vec<u32>,
+/// }
+///
+/// impl Fixture for DbFixture {
+///     fn set_up() -> Result<Self> {
+///         Ok(Self { rows: vec![1, 2, 3] })
+///     }
+///
+///     fn tear_down(self) -> Result<()> {
+///         verify_that!(self.rows, not(empty()))
+///     }
+/// }
+///
+/// #[googletest::test]
+/// fn should_read_rows(db: &mut DbFixture) {
+///     expect_that!(db.rows, len(eq(3)));
+/// }
+/// ```
+///
+/// The fixtures are set up in the order in which the parameters appear and
+/// torn down in the reverse order. If [`set_up`][Fixture::set_up] fails, the
+/// failure is recorded, the test body is not run, and the fixtures which were
+/// already set up are torn down.
+///
+/// [`tear_down`][Fixture::tear_down] runs once the fixture has been set up,
+/// even if the test body has failed or panicked (for example in
+/// [`assert_that!`][crate::assert_that]). A failure in `tear_down` is recorded
+/// as a non-fatal failure of the same test, alongside any failures from the
+/// test body. Since `tear_down` may run while a panic unwinds, it should not
+/// panic itself, which would abort the test binary.
+pub trait Fixture: Sized {
+    /// Creates the fixture before the test body runs.
+    fn set_up() -> crate::Result<Self>;
+
+    /// Releases the fixture after the test body has run.
+    fn tear_down(self) -> crate::Result<()>;
+}
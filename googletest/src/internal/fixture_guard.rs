@@ -0,0 +1,56 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::fixtures::Fixture;
+use crate::GoogleTestSupport;
+use std::ops::{Deref, DerefMut};
+
+/// Holds a fixture which has been set up and tears it down when dropped.
+///
+/// The test attribute macro passes the fixtures to the test body through this
+/// guard so that they are torn down even if the test body panics.
+///
+/// **For internal use only. API stablility is not guaranteed!**
+#[doc(hidden)]
+pub struct FixtureGuard<FixtureT: Fixture> {
+    fixture: Option<FixtureT>,
+}
+
+impl<FixtureT: Fixture> FixtureGuard<FixtureT> {
+    pub fn new(fixture: FixtureT) -> Self {
+        Self { fixture: Some(fixture) }
+    }
+}
+
+impl<FixtureT: Fixture> Deref for FixtureGuard<FixtureT> {
+    type Target = FixtureT;
+
+    fn deref(&self) -> &FixtureT {
+        self.fixture.as_ref().expect("The fixture is only taken when the guard is dropped")
+    }
+}
+
+impl<FixtureT: Fixture> DerefMut for FixtureGuard<FixtureT> {
+    fn deref_mut(&mut self) -> &mut FixtureT {
+        self.fixture.as_mut().expect("The fixture is only taken when the guard is dropped")
+    }
+}
+
+impl<FixtureT: Fixture> Drop for FixtureGuard<FixtureT> {
+    fn drop(&mut self) {
+        if let Some(fixture) = self.fixture.take() {
+            fixture.tear_down().and_log_failure();
+        }
+    }
+}
@@ -0,0 +1,198 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Writes a machine-readable record of each failure in a test annotated with
+//! `#[googletest::test]` to a JSON lines file.
+//!
+//! This is enabled by setting the environment variable
+//! `GOOGLETEST_JSON_OUTPUT_FILE` to the path of the file.
+//!
+//! Each failure is written as a single line containing a JSON object, which
+//! holds the parts of the failure as separate fields, so that tools need not
+//! parse the human-readable failure output. Fields which are not known for a
+//! given failure, for example the actual value of a failure produced by
+//! [`fail!`][crate::fail], are `null`.
+//!
+//! The file is opened in append mode and records from previous runs are not
+//! removed. Appends from tests running in parallel in the same test binary are
+//! serialised behind a process-wide lock, so their records do not interleave.
+//! Nothing coordinates appends from several test binaries running at the same
+//! time, so these should not share a file.
+
+use crate::internal::junit_xml::strip_ansi_escapes;
+use crate::internal::test_outcome::FailureDetails;
+use std::fmt::Write as _;
+use std::fs::{File, OpenOptions};
+use std::io::Write as _;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+const GOOGLETEST_JSON_OUTPUT_FILE: &str = "GOOGLETEST_JSON_OUTPUT_FILE";
+
+enum WriterState {
+    Uninitialized,
+    Disabled,
+    Enabled { path: PathBuf, file: File },
+}
+
+static WRITER: Mutex<WriterState> = Mutex::new(WriterState::Uninitialized);
+
+/// Returns whether failures are written to a JSON lines file.
+pub(crate) fn is_enabled() -> bool {
+    matches!(&*lock_writer(), WriterState::Enabled { .. })
+}
+
+/// Appends a record of a failure of the test `test_name` to the JSON lines
+/// file, if enabled.
+///
+/// The parameter `message` is the complete human-readable output of the
+/// failure. Errors writing the file are output to stderr but otherwise
+/// ignored, since they should not affect the result of the test.
+pub(crate) fn report(test_name: &str, message: &str, details: &FailureDetails) {
+    let mut state = lock_writer();
+    if let WriterState::Enabled { path, file } = &mut *state {
+        if let Err(error) = file.write_all(render_record(test_name, message, details).as_bytes()) {
+            eprintln!("Failed to write the JSON failure record to {}: {error}", path.display());
+        }
+    }
+}
+
+fn lock_writer() -> std::sync::MutexGuard<'static, WriterState> {
+    // A panic while holding the lock cannot leave the writer in an
+    // inconsistent state, so the lock is recovered from poisoning.
+    let mut state = WRITER.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    if let WriterState::Uninitialized = &*state {
+        *state = match std::env::var_os(GOOGLETEST_JSON_OUTPUT_FILE) {
+            Some(path) if !path.is_empty() => {
+                let path = PathBuf::from(path);
+                match OpenOptions::new().create(true).append(true).open(&path) {
+                    Ok(file) => WriterState::Enabled { path, file },
+                    Err(error) => {
+                        eprintln!(
+                            "Failed to open the JSON output file {}: {error}",
+                            path.display()
+                        );
+                        WriterState::Disabled
+                    }
+                }
+            }
+            _ => WriterState::Disabled,
+        };
+    }
+    state
+}
+
+/// Renders the record of a single failure as a line of JSON, including the
+/// terminating newline.
+fn render_record(test_name: &str, message: &str, details: &FailureDetails) -> String {
+    let source_location = details.source_location.as_ref();
+    let fields = [
+        ("test", json_string(test_name)),
+        ("actual_expression", json_optional_string(details.actual_expression)),
+        ("actual_value", json_optional_string(details.actual_value.as_deref())),
+        ("expected", json_optional_string(details.expected.as_deref())),
+        ("explanation", json_optional_string(details.explanation.as_deref())),
+        ("file", json_optional_string(source_location.map(|location| location.file()))),
+        ("line", json_optional_number(source_location.map(|location| location.line()))),
+        ("column", json_optional_number(source_location.map(|location| location.column()))),
+        ("message", json_string(message.trim_end())),
+    ];
+    let mut output = String::from("{");
+    for (index, (name, value)) in fields.iter().enumerate() {
+        if index > 0 {
+            output.push(',');
+        }
+        write!(output, "\"{name}\":{value}").unwrap();
+    }
+    output.push_str("}\n");
+    output
+}
+
+fn json_optional_string(value: Option<&str>) -> String {
+    value.map(json_string).unwrap_or_else(|| "null".into())
+}
+
+fn json_optional_number(value: Option<u32>) -> String {
+    value.map(|value| value.to_string()).unwrap_or_else(|| "null".into())
+}
+
+/// Renders `text` as a JSON string literal, without the escape sequences
+/// which colour diffs in failure output.
+fn json_string(text: &str) -> String {
+    let mut output = String::with_capacity(text.len() + 2);
+    output.push('"');
+    for c in strip_ansi_escapes(text).chars() {
+        match c {
+            '"' => output.push_str("\\\""),
+            '\\' => output.push_str("\\\\"),
+            '\n' => output.push_str("\\n"),
+            '\r' => output.push_str("\\r"),
+            '\t' => output.push_str("\\t"),
+            c if c.is_control() => write!(output, "\\u{:04x}", c as u32).unwrap(),
+            c => output.push(c),
+        }
+    }
+    output.push('"');
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{json_string, render_record};
+    use crate::internal::source_location::SourceLocation;
+    use crate::internal::test_outcome::FailureDetails;
+    use crate::prelude::*;
+
+    #[test]
+    fn renders_matcher_failure() -> Result<()> {
+        let details = FailureDetails {
+            actual_expression: Some("value"),
+            actual_value: Some("1".into()),
+            expected: Some("is equal to 2".into()),
+            explanation: Some("which isn't equal to 2".into()),
+            source_location: Some(SourceLocation::new("src/lib.rs", 10, 5)),
+        };
+
+        verify_that!(
+            render_record("tests::fails", "Value of: value\n", &details),
+            eq(concat!(
+                r#"{"test":"tests::fails","actual_expression":"value","actual_value":"1","#,
+                r#""expected":"is equal to 2","explanation":"which isn't equal to 2","#,
+                r#""file":"src/lib.rs","line":10,"column":5,"message":"Value of: value"}"#,
+                "\n",
+            ))
+        )
+    }
+
+    #[test]
+    fn renders_unknown_fields_as_null() -> Result<()> {
+        verify_that!(
+            render_record("tests::fails", "Error", &FailureDetails::default()),
+            eq(concat!(
+                r#"{"test":"tests::fails","actual_expression":null,"actual_value":null,"#,
+                r#""expected":null,"explanation":null,"file":null,"line":null,"column":null,"#,
+                r#""message":"Error"}"#,
+                "\n",
+            ))
+        )
+    }
+
+    #[test]
+    fn escapes_special_characters() -> Result<()> {
+        verify_that!(
+            json_string("\"a\\b\"\n\t\x01\x1B[1;31mred\x1B[0m"),
+            eq(r#""\"a\\b\"\n\t\u0001red""#)
+        )
+    }
+}
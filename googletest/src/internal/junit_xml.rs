@@ -0,0 +1,412 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Writes the results of tests annotated with `#[googletest::test]` to a
+//! JUnit XML file.
+//!
+//! This is enabled by setting the environment variable
+//! `GOOGLETEST_XML_OUTPUT_FILE` or, as Bazel does, `XML_OUTPUT_FILE` to the
+//! path of the file. The former takes precedence.
+//!
+//! The test binary writes one `<testsuite>` element containing a `<testcase>`
+//! element for each test which has finished so far. Since libtest offers no
+//! hook at the end of the test run, the whole file is rewritten after each
+//! test. It is written to a temporary file which is then renamed, so that the
+//! file is always a complete XML document with up to date counts in the
+//! `<testsuite>` element. Tests running in parallel in the same process
+//! serialise their updates. Each `<testcase>` element is rendered only once,
+//! but the time spent writing the file grows quadratically with the number of
+//! tests in the binary.
+//!
+//! If the file already exists when the first test finishes and contains the
+//! test suites of previous test binaries, for example when `cargo test` runs
+//! several test binaries in turn, then those test suites are preserved. A test
+//! suite with the same name as that of the running binary, typically from an
+//! earlier run of the same binary, is replaced. Concurrently running test
+//! binaries must use different files.
+
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::Duration;
+
+const GOOGLETEST_XML_OUTPUT_FILE: &str = "GOOGLETEST_XML_OUTPUT_FILE";
+const XML_OUTPUT_FILE: &str = "XML_OUTPUT_FILE";
+const XML_DECLARATION: &str = "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n";
+const TEST_SUITES_START: &str = "<testsuites>\n";
+const TEST_SUITES_END: &str = "</testsuites>\n";
+const TEST_SUITE_START: &str = "  <testsuite ";
+const TEST_SUITE_END: &str = "  </testsuite>\n";
+
+/// The result of a single test.
+pub(crate) struct TestCaseReport {
+    /// The name of the test as reported by libtest.
+    pub(crate) name: String,
+    pub(crate) duration: Duration,
+    /// The output of each failure of the test, in the order recorded.
+    pub(crate) failures: Vec<String>,
+}
+
+enum ReporterState {
+    Uninitialized,
+    Disabled,
+    Enabled(Reporter),
+}
+
+static REPORTER: Mutex<ReporterState> = Mutex::new(ReporterState::Uninitialized);
+
+/// Returns whether a JUnit XML file is written for this test run.
+pub(crate) fn is_enabled() -> bool {
+    matches!(&*lock_reporter(), ReporterState::Enabled(_))
+}
+
+/// Adds `test_case` to the JUnit XML file, if enabled.
+///
+/// Errors writing the file are output to stderr but otherwise ignored, since
+/// they should not affect the result of the test.
+pub(crate) fn report(test_case: TestCaseReport) {
+    let mut state = lock_reporter();
+    if let ReporterState::Enabled(reporter) = &mut *state {
+        reporter.test_suite.add(&test_case);
+        if let Err(error) = reporter.write() {
+            eprintln!("Failed to write the JUnit XML file {}: {error}", reporter.path.display());
+        }
+    }
+}
+
+fn lock_reporter() -> std::sync::MutexGuard<'static, ReporterState> {
+    // A panic while holding the lock cannot leave the reporter in an
+    // inconsistent state, so the lock is recovered from poisoning.
+    let mut state = REPORTER.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    if let ReporterState::Uninitialized = &*state {
+        *state = match output_path() {
+            Some(path) => ReporterState::Enabled(Reporter::new(path)),
+            None => ReporterState::Disabled,
+        };
+    }
+    state
+}
+
+fn output_path() -> Option<PathBuf> {
+    [GOOGLETEST_XML_OUTPUT_FILE, XML_OUTPUT_FILE]
+        .into_iter()
+        .filter_map(std::env::var_os)
+        .find(|path| !path.is_empty())
+        .map(PathBuf::from)
+}
+
+struct Reporter {
+    path: PathBuf,
+    /// The content of the file preceding the test suite of this binary.
+    preamble: String,
+    test_suite: TestSuiteReport,
+}
+
+impl Reporter {
+    fn new(path: PathBuf) -> Self {
+        let suite_name = suite_name();
+        let mut preamble = format!("{XML_DECLARATION}{TEST_SUITES_START}");
+        if let Ok(existing) = std::fs::read_to_string(&path) {
+            if existing.starts_with(XML_DECLARATION) {
+                preamble.push_str(&other_test_suites(&existing, &suite_name));
+            }
+        }
+        Self { path, preamble, test_suite: TestSuiteReport::new(suite_name) }
+    }
+
+    fn write(&self) -> std::io::Result<()> {
+        let temporary_path = temporary_path(&self.path);
+        std::fs::write(&temporary_path, self.render())?;
+        std::fs::rename(&temporary_path, &self.path)
+    }
+
+    fn render(&self) -> String {
+        let mut output = self.preamble.clone();
+        output.push_str(&self.test_suite.render());
+        output.push_str(TEST_SUITES_END);
+        output
+    }
+}
+
+/// Returns the `<testsuite>` elements in `existing` whose name is not
+/// `suite_name`.
+fn other_test_suites(existing: &str, suite_name: &str) -> String {
+    let own_start = format!("{TEST_SUITE_START}name=\"{}\" ", escape_attribute(suite_name));
+    let mut test_suites = String::new();
+    let mut rest = existing;
+    while let Some(start) = rest.find(TEST_SUITE_START) {
+        let Some(length) = rest[start..].find(TEST_SUITE_END) else {
+            break;
+        };
+        let end = start + length + TEST_SUITE_END.len();
+        if !rest[start..end].starts_with(&own_start) {
+            test_suites.push_str(&rest[start..end]);
+        }
+        rest = &rest[end..];
+    }
+    test_suites
+}
+
+/// The `<testsuite>` element of the running test binary.
+struct TestSuiteReport {
+    name: String,
+    test_count: usize,
+    failure_count: usize,
+    duration: Duration,
+    /// The rendered `<testcase>` elements of the tests reported so far.
+    test_cases: String,
+}
+
+impl TestSuiteReport {
+    fn new(name: String) -> Self {
+        Self {
+            name,
+            test_count: 0,
+            failure_count: 0,
+            duration: Duration::ZERO,
+            test_cases: String::new(),
+        }
+    }
+
+    fn add(&mut self, test_case: &TestCaseReport) {
+        self.test_count += 1;
+        if !test_case.failures.is_empty() {
+            self.failure_count += 1;
+        }
+        self.duration += test_case.duration;
+        self.test_cases.push_str(&render_test_case(&self.name, test_case));
+    }
+
+    fn render(&self) -> String {
+        let mut output = String::new();
+        writeln!(
+            output,
+            "{TEST_SUITE_START}name=\"{}\" tests=\"{}\" failures=\"{}\" errors=\"0\" \
+            time=\"{:.3}\">",
+            escape_attribute(&self.name),
+            self.test_count,
+            self.failure_count,
+            self.duration.as_secs_f64(),
+        )
+        .unwrap();
+        output.push_str(&self.test_cases);
+        output.push_str(TEST_SUITE_END);
+        output
+    }
+}
+
+/// Returns the name of the running test binary, without the hash which Cargo
+/// appends to it.
+fn suite_name() -> String {
+    let executable = std::env::current_exe().ok();
+    let name = executable
+        .as_deref()
+        .and_then(Path::file_stem)
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "googletest".into());
+    match name.rsplit_once('-') {
+        Some((base, hash)) if hash.len() == 16 && hash.chars().all(|c| c.is_ascii_hexdigit()) => {
+            base.into()
+        }
+        _ => name,
+    }
+}
+
+fn temporary_path(path: &Path) -> PathBuf {
+    let mut file_name = path.file_name().unwrap_or_default().to_os_string();
+    file_name.push(format!(".{}.tmp", std::process::id()));
+    path.with_file_name(file_name)
+}
+
+fn render_test_case(suite_name: &str, test_case: &TestCaseReport) -> String {
+    let mut output = String::new();
+    write!(
+        output,
+        "    <testcase name=\"{}\" classname=\"{}\" time=\"{:.3}\"",
+        escape_attribute(&test_case.name),
+        escape_attribute(suite_name),
+        test_case.duration.as_secs_f64(),
+    )
+    .unwrap();
+    if test_case.failures.is_empty() {
+        output.push_str("/>\n");
+        return output;
+    }
+    output.push_str(">\n");
+    for failure in &test_case.failures {
+        let failure = strip_ansi_escapes(failure);
+        let message = failure.lines().next().unwrap_or_default();
+        writeln!(
+            output,
+            "      <failure message=\"{}\">{}</failure>",
+            escape_attribute(message),
+            escape_text(failure.trim_end()),
+        )
+        .unwrap();
+    }
+    output.push_str("    </testcase>\n");
+    output
+}
+
+/// Escapes `text` for use in an XML attribute value.
+fn escape_attribute(text: &str) -> String {
+    escape_text(text).replace('"', "&quot;").replace('\n', "&#10;")
+}
+
+/// Escapes `text` for use in XML character data.
+///
+/// Control characters other than whitespace, which XML 1.0 cannot represent,
+/// are dropped.
+fn escape_text(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '\n' | '\t' | '\r' => escaped.push(c),
+            c if c.is_control() => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Removes the escape sequences which colour diffs in failure output.
+pub(crate) fn strip_ansi_escapes(text: &str) -> String {
+    let mut stripped = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1B' {
+            // Skip the control sequence up to and including its final byte.
+            for c in chars.by_ref() {
+                if c.is_ascii_alphabetic() {
+                    break;
+                }
+            }
+        } else {
+            stripped.push(c);
+        }
+    }
+    stripped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{other_test_suites, strip_ansi_escapes, TestCaseReport, TestSuiteReport};
+    use crate::prelude::*;
+    use std::time::Duration;
+
+    fn render_test_suite(suite_name: &str, test_cases: &[TestCaseReport]) -> String {
+        let mut test_suite = TestSuiteReport::new(suite_name.into());
+        for test_case in test_cases {
+            test_suite.add(test_case);
+        }
+        test_suite.render()
+    }
+
+    #[test]
+    fn renders_passing_test_case() -> Result<()> {
+        let test_cases = [TestCaseReport {
+            name: "tests::passes".into(),
+            duration: Duration::from_millis(12),
+            failures: vec![],
+        }];
+
+        verify_that!(
+            render_test_suite("my_tests", &test_cases),
+            eq(concat!(
+                r#"  <testsuite name="my_tests" tests="1" failures="0" errors="0" time="0.012">"#,
+                "\n",
+                r#"    <testcase name="tests::passes" classname="my_tests" time="0.012"/>"#,
+                "\n",
+                "  </testsuite>\n",
+            ))
+        )
+    }
+
+    #[test]
+    fn renders_each_failure_of_test_case() -> Result<()> {
+        let test_cases = [TestCaseReport {
+            name: "tests::fails".into(),
+            duration: Duration::from_millis(1),
+            failures: vec![
+                "Value of: a\nExpected: is equal to 1\n  at src/lib.rs:1:1\n".into(),
+                "Value of: b\nExpected: is equal to 2\n  at src/lib.rs:2:1\n".into(),
+            ],
+        }];
+
+        verify_that!(
+            render_test_suite("my_tests", &test_cases),
+            eq(concat!(
+                r#"  <testsuite name="my_tests" tests="1" failures="1" errors="0" time="0.001">"#,
+                "\n",
+                r#"    <testcase name="tests::fails" classname="my_tests" time="0.001">"#,
+                "\n",
+                r#"      <failure message="Value of: a">"#,
+                "Value of: a\nExpected: is equal to 1\n  at src/lib.rs:1:1</failure>\n",
+                r#"      <failure message="Value of: b">"#,
+                "Value of: b\nExpected: is equal to 2\n  at src/lib.rs:2:1</failure>\n",
+                "    </testcase>\n",
+                "  </testsuite>\n",
+            ))
+        )
+    }
+
+    #[test]
+    fn escapes_special_characters() -> Result<()> {
+        let test_cases = [TestCaseReport {
+            name: "tests::fails".into(),
+            duration: Duration::ZERO,
+            failures: vec!["Expected: is equal to \"<&>\"".into()],
+        }];
+
+        verify_that!(
+            render_test_suite("my_tests", &test_cases),
+            contains_substring(concat!(
+                r#"<failure message="Expected: is equal to &quot;&lt;&amp;&gt;&quot;">"#,
+                r#"Expected: is equal to "&lt;&amp;&gt;"</failure>"#,
+            ))
+        )
+    }
+
+    #[test]
+    fn keeps_only_test_suites_of_other_binaries() -> Result<()> {
+        let existing = concat!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n",
+            "<testsuites>\n",
+            r#"  <testsuite name="other_tests" tests="0" failures="0" errors="0" time="0.000">"#,
+            "\n  </testsuite>\n",
+            r#"  <testsuite name="my_tests" tests="0" failures="0" errors="0" time="0.000">"#,
+            "\n  </testsuite>\n",
+            "</testsuites>\n",
+        );
+
+        verify_that!(
+            other_test_suites(existing, "my_tests"),
+            eq(concat!(
+                r#"  <testsuite name="other_tests" tests="0" failures="0" errors="0" time="0.000">"#,
+                "\n  </testsuite>\n",
+            ))
+        )
+    }
+
+    #[test]
+    fn strips_colour_from_failures() -> Result<()> {
+        verify_that!(
+            strip_ansi_escapes("\x1B[1;31mred\x1B[0m and \x1B[3mitalic\x1B[0m"),
+            eq("red and italic")
+        )
+    }
+}
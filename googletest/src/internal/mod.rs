@@ -15,5 +15,9 @@
 #![doc(hidden)]
 
 pub(crate) mod description_renderer;
+pub mod fixture_guard;
+pub(crate) mod json_lines;
+pub(crate) mod junit_xml;
+pub(crate) mod panic_outcome;
 pub mod source_location;
 pub mod test_outcome;
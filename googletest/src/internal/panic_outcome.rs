@@ -0,0 +1,193 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    description::Description,
+    matcher::{Matcher, MatcherResult},
+};
+use std::any::Any;
+use std::fmt::{Debug, Formatter};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+/// Whether and how evaluating an expression panicked.
+///
+/// This is the actual value against which the macros
+/// [`verify_panics!`][crate::verify_panics] and
+/// [`expect_panic!`][crate::expect_panic] match.
+pub(crate) enum PanicOutcome {
+    DidNotPanic,
+    /// The expression panicked with the given message.
+    Panicked(String),
+    /// The expression panicked with a payload other than `&str` or `String`,
+    /// for example via [`std::panic::panic_any`].
+    PanickedWithOtherPayload,
+}
+
+impl PanicOutcome {
+    /// Runs `action`, catching any panic.
+    pub(crate) fn of(action: impl FnOnce()) -> Self {
+        match catch_unwind(AssertUnwindSafe(action)) {
+            Ok(_) => PanicOutcome::DidNotPanic,
+            Err(payload) => Self::from_payload(payload),
+        }
+    }
+
+    fn from_payload(payload: Box<dyn Any + Send>) -> Self {
+        match payload.downcast::<String>() {
+            Ok(message) => PanicOutcome::Panicked(*message),
+            Err(payload) => match payload.downcast_ref::<&'static str>() {
+                Some(message) => PanicOutcome::Panicked(message.to_string()),
+                None => PanicOutcome::PanickedWithOtherPayload,
+            },
+        }
+    }
+}
+
+impl Debug for PanicOutcome {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PanicOutcome::DidNotPanic => write!(f, "no panic"),
+            PanicOutcome::Panicked(message) => write!(f, "panic with message {message:?}"),
+            PanicOutcome::PanickedWithOtherPayload => write!(f, "panic with a non-string payload"),
+        }
+    }
+}
+
+/// A matcher which matches a [`PanicOutcome`] whose panic message matches
+/// `inner`.
+pub(crate) struct PanicsWithMatcher<InnerMatcherT> {
+    inner: InnerMatcherT,
+}
+
+impl<InnerMatcherT> PanicsWithMatcher<InnerMatcherT> {
+    pub(crate) fn new(inner: InnerMatcherT) -> Self {
+        Self { inner }
+    }
+}
+
+impl<InnerMatcherT: Matcher<ActualT = String>> Matcher for PanicsWithMatcher<InnerMatcherT> {
+    type ActualT = PanicOutcome;
+
+    fn matches(&self, actual: &PanicOutcome) -> MatcherResult {
+        match actual {
+            PanicOutcome::Panicked(message) => self.inner.matches(message),
+            PanicOutcome::DidNotPanic | PanicOutcome::PanickedWithOtherPayload => {
+                MatcherResult::NoMatch
+            }
+        }
+    }
+
+    fn explain_match(&self, actual: &PanicOutcome) -> Description {
+        match actual {
+            PanicOutcome::DidNotPanic => "which did not panic".into(),
+            PanicOutcome::Panicked(message) => Description::new()
+                .text("which panicked with a message")
+                .nested(self.inner.explain_match(message)),
+            PanicOutcome::PanickedWithOtherPayload => {
+                "which panicked with a payload which is not a string".into()
+            }
+        }
+    }
+
+    fn describe(&self, matcher_result: MatcherResult) -> Description {
+        match matcher_result {
+            MatcherResult::Match => {
+                format!("panics with a message which {}", self.inner.describe(MatcherResult::Match))
+                    .into()
+            }
+            MatcherResult::NoMatch => format!(
+                "doesn't panic or panics with a message which {}",
+                self.inner.describe(MatcherResult::NoMatch)
+            )
+            .into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PanicOutcome, PanicsWithMatcher};
+    use crate::matcher::{Matcher, MatcherResult};
+    use crate::prelude::*;
+    use indoc::indoc;
+
+    #[test]
+    fn matches_panic_with_str_message() -> Result<()> {
+        let matcher = PanicsWithMatcher::new(eq("Overflow"));
+
+        let result = matcher.matches(&PanicOutcome::of(|| panic!("Overflow")));
+
+        verify_that!(result, eq(MatcherResult::Match))
+    }
+
+    #[test]
+    fn matches_panic_with_formatted_message() -> Result<()> {
+        let matcher = PanicsWithMatcher::new(eq("Overflow at 3"));
+
+        let result = matcher.matches(&PanicOutcome::of(|| panic!("Overflow at {}", 3)));
+
+        verify_that!(result, eq(MatcherResult::Match))
+    }
+
+    #[test]
+    fn does_not_match_panic_with_other_message() -> Result<()> {
+        let matcher = PanicsWithMatcher::new(eq("Overflow"));
+
+        let result = matcher.matches(&PanicOutcome::of(|| panic!("Underflow")));
+
+        verify_that!(result, eq(MatcherResult::NoMatch))
+    }
+
+    #[test]
+    fn does_not_match_when_no_panic() -> Result<()> {
+        let matcher = PanicsWithMatcher::new(anything());
+
+        let result = matcher.matches(&PanicOutcome::of(|| {}));
+
+        verify_that!(result, eq(MatcherResult::NoMatch))
+    }
+
+    #[test]
+    fn does_not_match_panic_with_other_payload() -> Result<()> {
+        let matcher = PanicsWithMatcher::new(anything());
+
+        let result = matcher.matches(&PanicOutcome::of(|| std::panic::panic_any(123)));
+
+        verify_that!(result, eq(MatcherResult::NoMatch))
+    }
+
+    #[test]
+    fn explains_mismatching_message() -> Result<()> {
+        let matcher = PanicsWithMatcher::new(contains_substring("Overflow"));
+
+        verify_that!(
+            matcher.explain_match(&PanicOutcome::Panicked("Underflow".into())),
+            displays_as(eq(indoc!(
+                "
+                which panicked with a message
+                  which does not contain a substring \"Overflow\""
+            )))
+        )
+    }
+
+    #[test]
+    fn describes_itself() -> Result<()> {
+        let matcher = PanicsWithMatcher::new(contains_substring("Overflow"));
+
+        verify_that!(
+            matcher.describe(MatcherResult::Match),
+            displays_as(eq("panics with a message which contains a substring \"Overflow\""))
+        )
+    }
+}
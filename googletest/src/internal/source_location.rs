@@ -21,6 +21,7 @@ use std::fmt::{Display, Error, Formatter};
 ///
 /// **For internal use only. API stablility is not guaranteed!**
 #[doc(hidden)]
+#[derive(Clone, Copy)]
 pub struct SourceLocation {
     file: &'static str,
     line: u32,
@@ -35,6 +36,18 @@ impl SourceLocation {
     pub fn new(file: &'static str, line: u32, column: u32) -> Self {
         Self { file, line, column }
     }
+
+    pub(crate) fn file(&self) -> &'static str {
+        self.file
+    }
+
+    pub(crate) fn line(&self) -> u32 {
+        self.line
+    }
+
+    pub(crate) fn column(&self) -> u32 {
+        self.column
+    }
 }
 
 impl Display for SourceLocation {
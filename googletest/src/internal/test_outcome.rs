@@ -12,27 +12,55 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::internal::json_lines;
+use crate::internal::junit_xml::{self, TestCaseReport};
+use crate::internal::source_location::SourceLocation;
+use std::any::Any;
 use std::cell::{RefCell, RefMut};
 use std::fmt::{Debug, Display, Error, Formatter};
+use std::marker::PhantomData;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread::ThreadId;
 use std::thread_local;
+use std::time::Instant;
 
 /// The outcome hitherto of running a test.
 ///
 /// This is kept as a running record as the test progresses. One can access it
 /// with `TestOutcome::with_current_test_outcome`.
 ///
+/// The record is shared between the thread running the test and any threads
+/// to which it has been propagated with a
+/// [`TestContext`][crate::context::TestContext].
+///
 /// **For internal use only. API stablility is not guaranteed!**
 #[doc(hidden)]
-pub enum TestOutcome {
-    /// The test ran or is currently running and no assertions have failed.
-    Success,
-    /// The test ran or is currently running and at least one assertion has
-    /// failed.
-    Failure,
+pub struct TestOutcome {
+    /// Whether no assertions have failed so far.
+    is_success: AtomicBool,
+    /// The thread running the test itself.
+    test_thread: ThreadId,
+    /// The name of the test, if its result is to be written to a JUnit XML
+    /// file or its failures to a JSON lines file.
+    test_name: Option<String>,
+    start_time: Instant,
+    /// The output of each failure so far, if the result of the test is to be
+    /// written to a JUnit XML file.
+    failures: Option<Mutex<Vec<String>>>,
+    /// Whether the test has finished without panicking.
+    is_closed: AtomicBool,
+    /// The failures recorded so far, if they are to be captured rather than
+    /// output. See [`capture_failures`][crate::capture_failures].
+    captured_failures: Option<Mutex<Vec<TestAssertionFailure>>>,
+    /// Traces which annotate every failure of the test, after its scoped
+    /// traces, such as the parameter of a value-parameterized test.
+    traces: Mutex<Vec<String>>,
 }
 
 thread_local! {
-    static CURRENT_TEST_OUTCOME: RefCell<Option<TestOutcome>> = RefCell::new(None);
+    static CURRENT_TEST_OUTCOME: RefCell<Option<Arc<TestOutcome>>> = const { RefCell::new(None) };
+    static SCOPED_TRACES: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
 }
 
 impl TestOutcome {
@@ -45,10 +73,39 @@ impl TestOutcome {
     #[doc(hidden)]
     pub fn init_current_test_outcome() {
         Self::with_current_test_outcome(|mut current_test_outcome| {
-            *current_test_outcome = Some(TestOutcome::Success);
+            let test_thread = std::thread::current();
+            let write_junit_xml = junit_xml::is_enabled();
+            *current_test_outcome = Some(Arc::new(TestOutcome {
+                is_success: AtomicBool::new(true),
+                test_thread: test_thread.id(),
+                // libtest runs each test in a thread named after the test.
+                test_name: (write_junit_xml || json_lines::is_enabled())
+                    .then(|| test_thread.name().unwrap_or("<unnamed>").to_string()),
+                start_time: Instant::now(),
+                failures: write_junit_xml.then(|| Mutex::new(vec![])),
+                is_closed: AtomicBool::new(false),
+                captured_failures: None,
+                traces: Mutex::new(vec![]),
+            }));
         })
     }
 
+    /// Adds a trace with the given `message` and `source_location` to every
+    /// failure of the current test, in the same way as
+    /// [`scoped_trace!`][crate::scoped_trace] but for the rest of the test.
+    ///
+    /// This is intended only for use by the attribute macro
+    /// `#[googletest::test]`, which annotates the failures of a
+    /// value-parameterized or typed test with its parameter.
+    ///
+    /// **For internal use only. API stablility is not guaranteed!**
+    #[doc(hidden)]
+    pub fn add_trace_to_current_test(message: String, source_location: SourceLocation) {
+        let outcome = TestOutcome::current()
+            .expect("No test context found. This indicates a bug in GoogleTest.");
+        outcome.lock_traces().push(format!("Trace: {message}\n{source_location}"));
+    }
+
     /// Evaluates the current test's [`TestOutcome`], producing a suitable
     /// `Result`.
     ///
@@ -62,20 +119,30 @@ impl TestOutcome {
     ///
     /// **For internal use only. API stablility is not guaranteed!**
     #[doc(hidden)]
-    pub fn close_current_test_outcome<E: Display>(
+    pub fn close_current_test_outcome<E: Display + 'static>(
         inner_result: Result<(), E>,
     ) -> Result<(), TestFailure> {
         TestOutcome::with_current_test_outcome(|mut outcome| {
             let outer_result = match &*outcome {
-                Some(TestOutcome::Success) => match inner_result {
+                Some(outcome) if outcome.is_success() => match inner_result {
                     Ok(()) => Ok(()),
                     Err(_) => Err(TestFailure),
                 },
-                Some(TestOutcome::Failure) => Err(TestFailure),
+                Some(_) => Err(TestFailure),
                 None => {
                     panic!("No test context found. This indicates a bug in GoogleTest.")
                 }
             };
+            if let Some(outcome) = &*outcome {
+                if let Err(fatal_assertion_failure) = &inner_result {
+                    let no_details = FailureDetails::default();
+                    let details = (fatal_assertion_failure as &dyn Any)
+                        .downcast_ref::<TestAssertionFailure>()
+                        .map_or(&no_details, |failure| &*failure.details);
+                    outcome.record_failure(details, || format!("{fatal_assertion_failure}"));
+                }
+                outcome.is_closed.store(true, Ordering::Relaxed);
+            }
             if let Err(fatal_assertion_failure) = inner_result {
                 println!("{fatal_assertion_failure}");
             }
@@ -87,32 +154,143 @@ impl TestOutcome {
     /// Returns a `Result` corresponding to the outcome of the currently running
     /// test.
     pub(crate) fn get_current_test_outcome() -> Result<(), TestAssertionFailure> {
-        TestOutcome::with_current_test_outcome(|mut outcome| {
-            let outcome = outcome
-                .as_mut()
-                .expect("No test context found. This indicates a bug in GoogleTest.");
-            match outcome {
-                TestOutcome::Success => Ok(()),
-                TestOutcome::Failure => Err(TestAssertionFailure::create("Test failed".into())),
+        let is_success = TestOutcome::with_current_test_outcome(|outcome| {
+            outcome
+                .as_ref()
+                .expect("No test context found. This indicates a bug in GoogleTest.")
+                .is_success()
+        });
+        if is_success {
+            Ok(())
+        } else {
+            Err(TestAssertionFailure::create("Test failed".into()))
+        }
+    }
+
+    /// Returns the [`TestOutcome`] of the test running on the current thread,
+    /// if any.
+    pub(crate) fn current() -> Option<Arc<TestOutcome>> {
+        TestOutcome::with_current_test_outcome(|outcome| outcome.clone())
+    }
+
+    /// Runs `action` with the content of `slot` as the [`TestOutcome`] of the
+    /// current thread.
+    ///
+    /// Afterwards, even if `action` panics, `slot` receives the
+    /// [`TestOutcome`] of the current thread as `action` left it and the
+    /// previous one is restored. This allows a test context to follow a
+    /// computation, such as a future, from one thread to another.
+    pub(crate) fn with_outcome_in<T>(
+        slot: &mut Option<Arc<TestOutcome>>,
+        action: impl FnOnce() -> T,
+    ) -> T {
+        struct RestoreOnDrop<'a> {
+            slot: &'a mut Option<Arc<TestOutcome>>,
+            previous: Option<Arc<TestOutcome>>,
+        }
+
+        impl Drop for RestoreOnDrop<'_> {
+            fn drop(&mut self) {
+                let previous = self.previous.take();
+                *self.slot = TestOutcome::with_current_test_outcome(|mut outcome| {
+                    std::mem::replace(&mut *outcome, previous)
+                });
             }
-        })
+        }
+
+        let previous = TestOutcome::with_current_test_outcome(|mut outcome| {
+            std::mem::replace(&mut *outcome, slot.take())
+        });
+        let _restore = RestoreOnDrop { slot, previous };
+        action()
     }
 
-    /// Records that the currently running test has failed.
-    fn fail_current_test() {
-        TestOutcome::with_current_test_outcome(|mut outcome| {
+    /// Runs `action` in a new [`TestOutcome`] which captures the failures
+    /// recorded in it instead of outputting them.
+    ///
+    /// Returns the result of `action` and the captured failures, in the order
+    /// in which they were recorded.
+    pub(crate) fn capture_failures<T>(
+        action: impl FnOnce() -> T,
+    ) -> (T, Vec<TestAssertionFailure>) {
+        let traces = TestOutcome::current().map(|outcome| outcome.lock_traces().clone());
+        let outcome = Arc::new(TestOutcome {
+            is_success: AtomicBool::new(true),
+            test_thread: std::thread::current().id(),
+            test_name: None,
+            start_time: Instant::now(),
+            failures: None,
+            is_closed: AtomicBool::new(true),
+            captured_failures: Some(Mutex::new(vec![])),
+            traces: Mutex::new(traces.unwrap_or_default()),
+        });
+        let result = TestOutcome::with_outcome_in(&mut Some(outcome.clone()), action);
+        let captured_failures = std::mem::take(
+            &mut *outcome
+                .captured_failures
+                .as_ref()
+                .unwrap()
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner()),
+        );
+        (result, captured_failures)
+    }
+
+    /// Records that the currently running test has failed with `failure`.
+    ///
+    /// Returns the output for the failure, which `output` produces given
+    /// whether the current thread is the one running the test itself, or
+    /// `None` if the failure is captured rather than output.
+    fn fail_current_test(
+        failure: &TestAssertionFailure,
+        output: impl FnOnce(bool) -> String,
+    ) -> Option<String> {
+        TestOutcome::with_current_test_outcome(|outcome| {
             let outcome = outcome
-                .as_mut()
+                .as_ref()
                 .expect("No test context found. This indicates a bug in GoogleTest.");
-            *outcome = TestOutcome::Failure;
+            outcome.is_success.store(false, Ordering::Relaxed);
+            if let Some(captured_failures) = &outcome.captured_failures {
+                captured_failures
+                    .lock()
+                    .unwrap_or_else(|poisoned| poisoned.into_inner())
+                    .push(failure.clone());
+                return None;
+            }
+            let output = output(outcome.test_thread == std::thread::current().id());
+            outcome.record_failure(&failure.details, || output.clone());
+            Some(output)
         })
     }
 
+    /// Records a failure for the JUnit XML file and the JSON lines file, if
+    /// either is to be written.
+    fn record_failure(&self, details: &FailureDetails, output: impl FnOnce() -> String) {
+        let Some(test_name) = &self.test_name else {
+            return;
+        };
+        let output = output();
+        json_lines::report(test_name, &output, details);
+        if let Some(failures) = &self.failures {
+            failures.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).push(output);
+        }
+    }
+
+    fn is_success(&self) -> bool {
+        self.is_success.load(Ordering::Relaxed)
+    }
+
+    fn lock_traces(&self) -> std::sync::MutexGuard<'_, Vec<String>> {
+        self.traces.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
     /// Runs `action` with the [`TestOutcome`] for the currently running test.
     ///
     /// This is primarily intended for use by assertion macros like
     /// `expect_that!`.
-    fn with_current_test_outcome<T>(action: impl FnOnce(RefMut<Option<TestOutcome>>) -> T) -> T {
+    fn with_current_test_outcome<T>(
+        action: impl FnOnce(RefMut<Option<Arc<TestOutcome>>>) -> T,
+    ) -> T {
         CURRENT_TEST_OUTCOME.with(|current_test_outcome| action(current_test_outcome.borrow_mut()))
     }
 
@@ -123,13 +301,77 @@ impl TestOutcome {
                 "
 No test context found.
  * Did you annotate the test with googletest::test?
- * Is the assertion running in the original test thread?
+ * Is the assertion running in the original test thread, or in a thread spawned
+   with googletest::context::spawn or googletest::context::spawn_scoped?
 ",
             );
         })
     }
 }
 
+impl Drop for TestOutcome {
+    /// Writes the result of the test to the JUnit XML file, if enabled.
+    ///
+    /// This happens once the test and every thread and future sharing its
+    /// outcome have finished, including when the test has panicked.
+    fn drop(&mut self) {
+        let (Some(name), Some(failures)) = (self.test_name.take(), self.failures.take()) else {
+            return;
+        };
+        let mut failures = failures.into_inner().unwrap_or_else(|poisoned| poisoned.into_inner());
+        if !*self.is_closed.get_mut() {
+            failures.push("The test panicked. See the test output for the panic message.".into());
+        }
+        junit_xml::report(TestCaseReport { name, duration: self.start_time.elapsed(), failures });
+    }
+}
+
+/// A guard which annotates every [`TestAssertionFailure`] created on the
+/// current thread while it is alive with its message and source location.
+///
+/// This is created by the macro [`scoped_trace!`][crate::scoped_trace].
+///
+/// **For internal use only. API stablility is not guaranteed!**
+#[doc(hidden)]
+#[must_use = "The trace is removed as soon as the guard is dropped."]
+pub struct ScopedTrace {
+    // The trace is kept in a thread-local stack, so the guard must be dropped
+    // on the thread which created it.
+    _not_send: PhantomData<*const ()>,
+}
+
+impl ScopedTrace {
+    /// Pushes a trace with the given `message` and `source_location` onto the
+    /// stack of active traces of the current thread.
+    ///
+    /// **For internal use only. API stablility is not guaranteed!**
+    pub fn new(message: String, source_location: SourceLocation) -> Self {
+        SCOPED_TRACES.with(|traces| {
+            traces.borrow_mut().push(format!("Trace: {message}\n{source_location}"))
+        });
+        Self { _not_send: PhantomData }
+    }
+
+    /// Returns the active traces of the current thread, innermost first,
+    /// followed by those of the current test.
+    fn current_traces() -> Vec<String> {
+        let mut traces =
+            SCOPED_TRACES.with(|traces| traces.borrow().iter().rev().cloned().collect::<Vec<_>>());
+        if let Some(outcome) = TestOutcome::current() {
+            traces.extend(outcome.lock_traces().iter().cloned());
+        }
+        traces
+    }
+}
+
+impl Drop for ScopedTrace {
+    fn drop(&mut self) {
+        SCOPED_TRACES.with(|traces| {
+            traces.borrow_mut().pop();
+        })
+    }
+}
+
 /// A marking struct indicating that a test has failed.
 ///
 /// This exists to implement the [Error][std::error::Error] trait. It displays
@@ -162,28 +404,104 @@ pub struct TestAssertionFailure {
     /// A human-readable formatted string describing the error.
     pub description: String,
     pub custom_message: Option<String>,
+    /// The scoped traces which were active when the failure was created,
+    /// innermost first, followed by the traces of the test.
+    pub traces: Vec<String>,
+    /// The parts of the failure which are reported separately in the JSON
+    /// lines file.
+    pub details: Box<FailureDetails>,
 }
 
 impl TestAssertionFailure {
     /// Creates a new instance with the given `description`.
     ///
+    /// The instance records the scoped traces active on the current thread and
+    /// the traces of the current test.
+    ///
     /// **For internal use only. API stablility is not guaranteed!**
     pub fn create(description: String) -> Self {
-        Self { description, custom_message: None }
+        Self {
+            description,
+            custom_message: None,
+            traces: ScopedTrace::current_traces(),
+            details: Box::default(),
+        }
+    }
+
+    /// Attaches the given `details` to this instance.
+    ///
+    /// **For internal use only. API stablility is not guaranteed!**
+    pub fn with_details(self, details: FailureDetails) -> Self {
+        Self { details: Box::new(details), ..self }
     }
 
     pub(crate) fn log(&self) {
-        TestOutcome::fail_current_test();
-        println!("{}", self);
+        let output = TestOutcome::fail_current_test(self, |is_test_thread| {
+            if is_test_thread {
+                format!("{}", self)
+            } else {
+                let thread = std::thread::current();
+                format!("Failure in thread '{}':\n{}", thread.name().unwrap_or("<unnamed>"), self)
+            }
+        });
+        if let Some(output) = output {
+            println!("{}", output);
+        }
     }
 }
 
+/// The value of a block of code run by
+/// [`capture_failures`][crate::capture_failures].
+///
+/// This is implemented for `()` and for [`Result<()>`][crate::Result], whose
+/// `Err` variant is a fatal failure of the block.
+///
+/// **For internal use only. API stablility is not guaranteed!**
+#[doc(hidden)]
+pub trait CapturedResult {
+    /// Returns the fatal failure of the block, if any.
+    fn into_failure(self) -> Option<TestAssertionFailure>;
+}
+
+impl CapturedResult for () {
+    fn into_failure(self) -> Option<TestAssertionFailure> {
+        None
+    }
+}
+
+impl CapturedResult for Result<(), TestAssertionFailure> {
+    fn into_failure(self) -> Option<TestAssertionFailure> {
+        self.err()
+    }
+}
+
+/// The parts of a [`TestAssertionFailure`], as far as they are known.
+///
+/// **For internal use only. API stablility is not guaranteed!**
+#[doc(hidden)]
+#[derive(Clone, Default)]
+pub struct FailureDetails {
+    /// The expression which was evaluated to obtain the actual value.
+    pub actual_expression: Option<&'static str>,
+    /// The `Debug` output of the actual value.
+    pub actual_value: Option<String>,
+    /// The description of the values which were expected.
+    pub expected: Option<String>,
+    /// Why the actual value is not one of the expected values.
+    pub explanation: Option<String>,
+    /// The location of the assertion in the source code.
+    pub source_location: Option<SourceLocation>,
+}
+
 impl Display for TestAssertionFailure {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
         writeln!(f, "{}", self.description)?;
         if let Some(custom_message) = &self.custom_message {
             writeln!(f, "{}", custom_message)?;
         }
+        for trace in &self.traces {
+            writeln!(f, "{}", trace)?;
+        }
         Ok(())
     }
 }
@@ -21,7 +21,11 @@ extern crate quickcheck;
 
 #[macro_use]
 pub mod assertions;
+pub mod context;
+#[cfg(target_os = "linux")]
+pub mod death_test;
 pub mod description;
+pub mod fixtures;
 pub mod internal;
 pub mod matcher;
 pub mod matcher_support;
@@ -42,19 +46,28 @@ pub mod matchers;
 /// }
 /// ```
 pub mod prelude {
+    #[cfg(target_os = "linux")]
+    pub use super::death_test::{exited_with_code, killed_by_signal};
+    pub use super::fixtures::Fixture;
     pub use super::matcher::Matcher;
     pub use super::matchers::*;
+    pub use super::capture_failures;
     pub use super::verify_current_test_outcome;
     pub use super::GoogleTestSupport;
     pub use super::IntoTestResult;
     pub use super::Result;
     // Assert macros
-    pub use super::{assert_that, expect_pred, expect_that, fail, verify_pred, verify_that};
+    pub use super::{
+        assert_that, expect_panic, expect_pred, expect_that, fail, scoped_trace, verify_panics,
+        verify_pred, verify_that,
+    };
+    #[cfg(target_os = "linux")]
+    pub use super::{expect_exit, verify_exit};
 }
 
 pub use googletest_macro::test;
 
-use internal::test_outcome::{TestAssertionFailure, TestOutcome};
+use internal::test_outcome::{CapturedResult, TestAssertionFailure, TestOutcome};
 
 /// A `Result` whose `Err` variant indicates a test failure.
 ///
@@ -112,6 +125,70 @@ pub fn verify_current_test_outcome() -> Result<()> {
     TestOutcome::get_current_test_outcome()
 }
 
+/// Runs `action` and returns the assertion failures which it records, rather
+/// than recording them as failures of the current test.
+///
+/// This allows one to check the failures which assertions produce, for
+/// example to test the descriptions and explanations of a custom matcher:
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # /* The attribute macro would prevent the function from being compiled in a doctest.
+/// #[googletest::test]
+/// # */
+/// fn should_explain_mismatch() -> Result<()> {
+///     let failures = capture_failures(|| {
+///         expect_that!(2, eq(3));
+///         expect_that!(3, eq(3));
+///     });
+///
+///     verify_that!(
+///         failures,
+///         elements_are![displays_as(contains_substring("which isn't equal to 3"))]
+///     )
+/// }
+/// # should_explain_mismatch().unwrap();
+/// ```
+///
+/// The block `action` runs in a nested test context, so it may contain
+/// non-fatal assertions such as [`expect_that!`][crate::expect_that] even if
+/// the current test is not annotated with [`googletest::test`][crate::test].
+/// Failures recorded in threads spawned from the block with
+/// [`context::spawn`] or [`context::spawn_scoped`] are captured as well.
+///
+/// The block may also evaluate to a [`Result<()>`][Result], for example to use
+/// the `?` operator with fatal assertions such as
+/// [`verify_that!`][crate::verify_that]. A fatal failure is then returned
+/// after all non-fatal failures:
+///
+/// ```
+/// # use googletest::prelude::*;
+/// let failures = capture_failures(|| {
+///     expect_that!(1, eq(2));
+///     verify_that!(3, eq(4))?;
+///     verify_that!(5, eq(6))
+/// });
+///
+/// # verify_that!(
+/// #     failures,
+/// #     elements_are![
+/// #         displays_as(contains_substring("Value of: 1")),
+/// #         displays_as(contains_substring("Value of: 3")),
+/// #     ]
+/// # ).unwrap();
+/// ```
+///
+/// The captured failures are neither output nor written to the JUnit XML or
+/// JSON lines files. Panics in the block, such as those of
+/// [`assert_that!`][crate::assert_that], are not caught.
+pub fn capture_failures<R: CapturedResult>(
+    action: impl FnOnce() -> R,
+) -> Vec<TestAssertionFailure> {
+    let (result, mut failures) = TestOutcome::capture_failures(action);
+    failures.extend(result.into_failure());
+    failures
+}
+
 /// Adds to `Result` support for GoogleTest Rust functionality.
 pub trait GoogleTestSupport {
     /// If `self` is a `Result::Err`, writes to `stdout` a failure report
@@ -16,9 +16,9 @@
 
 use crate::description::Description;
 use crate::internal::source_location::SourceLocation;
-use crate::internal::test_outcome::TestAssertionFailure;
+use crate::internal::test_outcome::{FailureDetails, TestAssertionFailure};
 use crate::matchers::__internal_unstable_do_not_depend_on_these::ConjunctionMatcher;
-// use crate::matchers::__internal_unstable_do_not_depend_on_these::DisjunctionMatcher;
+use crate::matchers::__internal_unstable_do_not_depend_on_these::DisjunctionMatcher;
 use std::fmt::Debug;
 
 /// An interface for checking an arbitrary condition on a datum.
@@ -189,9 +189,7 @@ pub trait Matcher {
     /// ```
     // TODO(b/264518763): Replace the return type with impl Matcher and reduce
     // visibility of DisjunctionMatcher once impl in return position in trait
-    // methods is stable.    
-    #[cfg(do_not_compile)]
-
+    // methods is stable.
     fn or<Right: Matcher<ActualT = Self::ActualT>>(
         self,
         right: Right,
@@ -224,16 +222,25 @@ pub(crate) fn create_assertion_failure<T: Debug + ?Sized>(
     } else {
         actual_formatted
     };
+    let expected = matcher.describe(MatcherResult::Match);
+    let explanation = matcher.explain_match(actual);
+    let details = FailureDetails {
+        actual_expression: Some(actual_expr),
+        actual_value: Some(actual_formatted.clone()),
+        expected: Some(expected.to_string()),
+        explanation: Some(explanation.to_string()),
+        source_location: Some(source_location),
+    };
     TestAssertionFailure::create(format!(
         "\
 Value of: {actual_expr}
-Expected: {}
+Expected: {expected}
 Actual: {actual_formatted},
 {}
 {source_location}",
-        matcher.describe(MatcherResult::Match),
-        matcher.explain_match(actual).indent(),
+        explanation.indent(),
     ))
+    .with_details(details)
 }
 
 /// The result of applying a [`Matcher`] on an actual value.
@@ -279,7 +279,6 @@ impl<T: Clone> Path<T> {
         }
     }
 }
-#[cfg(do_not_compile)]
 
 #[cfg(test)]
 mod tests {
@@ -0,0 +1,70 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::{btree_map, hash_map, BTreeMap, HashMap};
+use std::hash::{BuildHasher, Hash};
+
+/// A map on which the map matchers [`has_entry`][crate::matchers::has_entry],
+/// [`keys`][crate::matchers::keys] and [`values`][crate::matchers::values]
+/// operate.
+///
+/// This is implemented for [`HashMap`] and [`BTreeMap`]. The trait is not
+/// exported, so it cannot be implemented outside of this crate.
+pub trait Map {
+    /// The type of the keys of the map.
+    type Key;
+
+    /// The type of the values of the map.
+    type Value;
+
+    /// The iterator over the entries of the map returned by
+    /// [`Map::entries`].
+    type Entries<'a>: Iterator<Item = (&'a Self::Key, &'a Self::Value)>
+    where
+        Self: 'a;
+
+    /// Returns the value to which the map maps `key`, if any.
+    fn get(&self, key: &Self::Key) -> Option<&Self::Value>;
+
+    /// Returns an iterator over the entries of the map in its iteration order.
+    fn entries(&self) -> Self::Entries<'_>;
+}
+
+impl<KeyT: Eq + Hash, ValueT, S: BuildHasher> Map for HashMap<KeyT, ValueT, S> {
+    type Key = KeyT;
+    type Value = ValueT;
+    type Entries<'a> = hash_map::Iter<'a, KeyT, ValueT> where Self: 'a;
+
+    fn get(&self, key: &KeyT) -> Option<&ValueT> {
+        HashMap::get(self, key)
+    }
+
+    fn entries(&self) -> Self::Entries<'_> {
+        self.iter()
+    }
+}
+
+impl<KeyT: Ord, ValueT> Map for BTreeMap<KeyT, ValueT> {
+    type Key = KeyT;
+    type Value = ValueT;
+    type Entries<'a> = btree_map::Iter<'a, KeyT, ValueT> where Self: 'a;
+
+    fn get(&self, key: &KeyT) -> Option<&ValueT> {
+        BTreeMap::get(self, key)
+    }
+
+    fn entries(&self) -> Self::Entries<'_> {
+        self.iter()
+    }
+}
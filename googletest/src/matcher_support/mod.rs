@@ -20,5 +20,6 @@
 
 pub(crate) mod count_elements;
 pub(crate) mod edit_distance;
+pub(crate) mod map;
 pub(crate) mod summarize_diff;
 pub(crate) mod zipped_iterator;
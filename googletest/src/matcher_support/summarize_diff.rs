@@ -80,6 +80,129 @@ pub(crate) fn create_diff_reversed(
     }
 }
 
+/// Returns a hex dump of the actual and expected byte sequences in which the
+/// differing bytes are highlighted.
+///
+/// The two byte sequences are compared position by position and shown in rows
+/// of 16 bytes, each followed by its ASCII rendering. Rows which are equal in
+/// both are shown once, with long runs of them elided. Differing bytes are
+/// coloured if stdout supports it and otherwise marked by `^^` on the line
+/// below.
+///
+/// If the two byte sequences are equal, this returns the empty string.
+pub(crate) fn create_hex_diff(actual: &[u8], expected: &[u8]) -> Cow<'static, str> {
+    if actual == expected {
+        return "".into();
+    }
+    let mut summary = SummaryBuilder::default();
+    let mut common_rows = vec![];
+    let row_count = (actual.len().max(expected.len()) + HEX_ROW_SIZE - 1) / HEX_ROW_SIZE;
+    for row in 0..row_count {
+        let offset = row * HEX_ROW_SIZE;
+        let actual_row = hex_row(actual, offset);
+        let expected_row = hex_row(expected, offset);
+        if actual_row == expected_row {
+            common_rows.push(format_hex_row(offset, actual_row));
+            continue;
+        }
+        Buffer::flush_common_lines(common_rows.iter().map(String::as_str).collect(), &mut summary);
+        common_rows.clear();
+
+        let differs = |index: usize| actual_row.get(index) != expected_row.get(index);
+        if !actual_row.is_empty() {
+            summary.new_line_for_actual();
+            push_hex_row(
+                &mut summary,
+                offset,
+                actual_row,
+                differs,
+                SummaryBuilder::push_str_actual_only,
+            );
+        }
+        if !expected_row.is_empty() {
+            summary.new_line_for_expected();
+            push_hex_row(
+                &mut summary,
+                offset,
+                expected_row,
+                differs,
+                SummaryBuilder::push_str_expected_only,
+            );
+        }
+        if !stdout_supports_color() {
+            summary.new_line();
+            summary.push_str(
+                hex_row_markers(actual_row.len().max(expected_row.len()), differs).trim_end(),
+            );
+        }
+    }
+    Buffer::flush_common_lines(common_rows.iter().map(String::as_str).collect(), &mut summary);
+    format!("\n{}{}", summary_header(), summary.summary).into()
+}
+
+const HEX_ROW_SIZE: usize = 16;
+
+fn hex_row(bytes: &[u8], offset: usize) -> &[u8] {
+    let start = offset.min(bytes.len());
+    let end = (offset + HEX_ROW_SIZE).min(bytes.len());
+    &bytes[start..end]
+}
+
+// Lays out one row of the hex dump, calling `push` for each group of
+// characters with whether it stands for a differing byte.
+fn layout_hex_row(
+    offset: usize,
+    row: &[u8],
+    differs: impl Fn(usize) -> bool,
+    mut push: impl FnMut(&str, bool),
+) {
+    push(&format!("{offset:08x}: "), false);
+    for index in 0..HEX_ROW_SIZE {
+        if index == HEX_ROW_SIZE / 2 {
+            push(" ", false);
+        }
+        match row.get(index) {
+            Some(byte) => push(&format!("{byte:02x}"), differs(index)),
+            None => push("  ", false),
+        }
+        push(" ", false);
+    }
+    push("|", false);
+    for (index, byte) in row.iter().enumerate() {
+        let character = if byte.is_ascii_graphic() || *byte == b' ' { *byte as char } else { '.' };
+        push(character.encode_utf8(&mut [0; 4]), differs(index));
+    }
+    push("|", false);
+}
+
+fn format_hex_row(offset: usize, row: &[u8]) -> String {
+    let mut line = String::new();
+    layout_hex_row(offset, row, |_| false, |text, _| line.push_str(text));
+    line
+}
+
+fn push_hex_row(
+    summary: &mut SummaryBuilder,
+    offset: usize,
+    row: &[u8],
+    differs: impl Fn(usize) -> bool,
+    highlight: fn(&mut SummaryBuilder, &str),
+) {
+    layout_hex_row(offset, row, differs, |text, differs| {
+        if differs { highlight(summary, text) } else { summary.push_str(text) }
+    });
+}
+
+fn hex_row_markers(row_len: usize, differs: impl Fn(usize) -> bool) -> String {
+    let row = vec![0; row_len];
+    let mut line = String::new();
+    layout_hex_row(0, &row, &differs, |text, differs| {
+        let marker = if differs { '^' } else { ' ' };
+        line.extend(std::iter::repeat(marker).take(text.chars().count()))
+    });
+    line
+}
+
 // Produces the header, with or without coloring depending on
 // stdout_supports_color()
 fn summary_header() -> Cow<'static, str> {
@@ -105,11 +228,11 @@ struct BufferedSummary<'a> {
 impl<'a> BufferedSummary<'a> {
     // Appends a new line which is common to both actual and expected.
     fn feed_common_lines(&mut self, common_line: &'a str) {
-        if let Buffer::CommonLineBuffer(ref mut common_lines) = self.buffer {
+        if let Buffer::CommonLines(ref mut common_lines) = self.buffer {
             common_lines.push(common_line);
         } else {
             self.flush_buffer();
-            self.buffer = Buffer::CommonLineBuffer(vec![common_line]);
+            self.buffer = Buffer::CommonLines(vec![common_line]);
         }
     }
 
@@ -227,9 +350,11 @@ impl<'a> Display for BufferedSummary<'a> {
     }
 }
 
+#[derive(Default)]
 enum Buffer<'a> {
+    #[default]
     Empty,
-    CommonLineBuffer(Vec<&'a str>),
+    CommonLines(Vec<&'a str>),
     ExtraActualLineChunk(&'a str),
     ExtraExpectedLineChunk(&'a str),
 }
@@ -238,7 +363,7 @@ impl<'a> Buffer<'a> {
     fn flush(&mut self, summary: &mut SummaryBuilder) {
         match self {
             Buffer::Empty => {}
-            Buffer::CommonLineBuffer(common_lines) => {
+            Buffer::CommonLines(common_lines) => {
                 Self::flush_common_lines(std::mem::take(common_lines), summary);
             }
             Buffer::ExtraActualLineChunk(extra_actual) => {
@@ -288,12 +413,6 @@ impl<'a> Buffer<'a> {
     }
 }
 
-impl<'a> Default for Buffer<'a> {
-    fn default() -> Self {
-        Self::Empty
-    }
-}
-
 #[rustversion::since(1.70)]
 fn stdout_supports_color() -> bool {
     match (is_env_var_set("NO_COLOR"), is_env_var_set("FORCE_COLOR")) {
@@ -507,6 +626,68 @@ mod tests {
         )
     }
 
+    #[test]
+    #[parallel]
+    fn create_hex_diff_equal_bytes() -> Result<()> {
+        verify_that!(create_hex_diff(b"same", b"same"), eq(""))
+    }
+
+    #[test]
+    #[parallel]
+    fn create_hex_diff_marks_differing_bytes() -> Result<()> {
+        verify_that!(
+            create_hex_diff(b"GET /a\r\n", b"PUT /a\r\n"),
+            eq(indoc! {
+                "
+
+                Difference(-actual / +expected):
+                -00000000: 47 45 54 20 2f 61 0d 0a                          |GET /a..|
+                +00000000: 50 55 54 20 2f 61 0d 0a                          |PUT /a..|
+                           ^^ ^^                                             ^^"
+            })
+        )
+    }
+
+    #[test]
+    #[parallel]
+    fn create_hex_diff_shows_extra_actual_bytes() -> Result<()> {
+        verify_that!(
+            create_hex_diff(b"0123456789abcdefXY", b"0123456789abcdef"),
+            eq(indoc! {
+                "
+
+                Difference(-actual / +expected):
+                 00000000: 30 31 32 33 34 35 36 37  38 39 61 62 63 64 65 66 |0123456789abcdef|
+                -00000010: 58 59                                            |XY|
+                           ^^ ^^                                             ^^"
+            })
+        )
+    }
+
+    #[test]
+    #[parallel]
+    fn create_hex_diff_omits_common_rows() -> Result<()> {
+        let actual = [0; 128];
+        let mut expected = actual;
+        expected[127] = 0xff;
+        verify_that!(
+            create_hex_diff(&actual, &expected),
+            eq(indoc! {
+                "
+
+                Difference(-actual / +expected):
+                 00000000: 00 00 00 00 00 00 00 00  00 00 00 00 00 00 00 00 |................|
+                 00000010: 00 00 00 00 00 00 00 00  00 00 00 00 00 00 00 00 |................|
+                 <---- 3 common lines omitted ---->
+                 00000050: 00 00 00 00 00 00 00 00  00 00 00 00 00 00 00 00 |................|
+                 00000060: 00 00 00 00 00 00 00 00  00 00 00 00 00 00 00 00 |................|
+                -00000070: 00 00 00 00 00 00 00 00  00 00 00 00 00 00 00 00 |................|
+                +00000070: 00 00 00 00 00 00 00 00  00 00 00 00 00 00 00 ff |................|
+                                                                         ^^                 ^"
+            })
+        )
+    }
+
     // Test with color enabled.
 
     struct ForceColor;
@@ -576,4 +757,21 @@ mod tests {
             })
         )
     }
+
+    #[test]
+    #[serial]
+    fn create_hex_diff_with_color() -> Result<()> {
+        let _keep = force_color();
+
+        verify_that!(
+            create_hex_diff(b"ab", b"ac"),
+            eq(indoc! {
+                "
+
+                Difference(-\x1B[1;31mactual\x1B[0m / +\x1B[1;32mexpected\x1B[0m):
+                -00000000: 61 \x1B[1;31m62\x1B[0m                                            |a\x1B[1;31mb\x1B[0m|
+                +00000000: 61 \x1B[1;32m63\x1B[0m                                            |a\x1B[1;32mc\x1B[0m|"
+            })
+        )
+    }
 }
@@ -71,8 +71,8 @@ pub mod internal {
     use crate::matchers::anything;
     use std::fmt::Debug;
 
-    /// A matcher which matches an input value matched by all matchers in the
-    /// array `components`.
+    /// A matcher which matches an input value matched by at least one matcher
+    /// in the array `components`.
     ///
     /// For internal use only. API stablility is not guaranteed!
     #[doc(hidden)]
@@ -83,7 +83,7 @@ pub mod internal {
     impl<'a, T: Debug + ?Sized, const N: usize> AnyMatcher<'a, T, N> {
         /// Constructs an [`AnyMatcher`] with the given component matchers.
         ///
-        /// Intended for use only by the [`all`] macro.
+        /// Intended for use only by the [`any`] macro.
         pub fn new(components: [Box<dyn Matcher<ActualT = T> + 'a>; N]) -> Self {
             Self { components }
         }
@@ -0,0 +1,367 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// There are no visible documentation elements in this module; the declarative
+// macro is documented in the matchers module.
+#![doc(hidden)]
+
+/// Matches a string containing a match of the given regular expression whose
+/// capture groups are matched by the given matchers.
+///
+/// The capture groups can be referenced by position. The first matcher then
+/// applies to the capture group `1`, the second to the capture group `2`, and
+/// so on:
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # fn should_pass() -> Result<()> {
+/// verify_that!("key=123", captures_regex!(r"(\w+)=(\d+)", [eq("key"), starts_with("1")]))?;
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// ```
+///
+/// Named capture groups can also be referenced by their name:
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # fn should_pass() -> Result<()> {
+/// verify_that!(
+///     "key=123",
+///     captures_regex!(r"(?P<key>\w+)=(?P<value>\d+)", ["value" => eq("123")])
+/// )?;
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// ```
+///
+/// As with [`contains_regex`][crate::matchers::contains_regex], the regular
+/// expression only needs to match a substring of the actual value. The
+/// capture groups are taken from its leftmost match. A capture group which
+/// does not participate in the match is never matched.
+///
+/// The inner matchers receive the captured text as a `String`.
+///
+/// Panics if the given pattern is not a syntactically valid regular
+/// expression, or if it does not have one of the referenced capture groups.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __captures_regex {
+    ($pattern:expr, [$($name:literal => $matcher:expr),* $(,)?]) => {{
+        use $crate::matchers::__internal_unstable_do_not_depend_on_these::{
+            CaptureGroup, CapturesRegexMatcher,
+        };
+        CapturesRegexMatcher::new(
+            $pattern,
+            vec![$((CaptureGroup::Name($name), Box::new($matcher))),*],
+        )
+    }};
+    ($pattern:expr, [$($matcher:expr),* $(,)?]) => {{
+        use $crate::matchers::__internal_unstable_do_not_depend_on_these::CapturesRegexMatcher;
+        CapturesRegexMatcher::with_numbered_groups($pattern, vec![$(Box::new($matcher)),*])
+    }};
+}
+
+/// Module for use only by the macros in this module.
+///
+/// **For internal use only. API stablility is not guaranteed!**
+#[doc(hidden)]
+pub mod internal {
+    use crate::description::Description;
+    use crate::matcher::{Matcher, MatcherResult};
+    use regex::{Captures, Regex};
+    use std::fmt::{Debug, Display};
+    use std::marker::PhantomData;
+    use std::ops::Deref;
+
+    /// A reference to a capture group of a regular expression.
+    ///
+    /// **For internal use only. API stablility is not guaranteed!**
+    #[doc(hidden)]
+    pub enum CaptureGroup {
+        Index(usize),
+        Name(&'static str),
+    }
+
+    impl CaptureGroup {
+        fn text(&self, captures: &Captures) -> Option<String> {
+            match self {
+                CaptureGroup::Index(index) => captures.get(*index),
+                CaptureGroup::Name(name) => captures.name(name),
+            }
+            .map(|m| m.as_str().to_string())
+        }
+    }
+
+    impl Display for CaptureGroup {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            match self {
+                CaptureGroup::Index(index) => write!(f, "#{index}"),
+                CaptureGroup::Name(name) => write!(f, "{name:?}"),
+            }
+        }
+    }
+
+    type GroupMatcher<'a> = Box<dyn Matcher<ActualT = String> + 'a>;
+
+    /// This struct is meant to be used only by the macro `captures_regex!`.
+    ///
+    /// **For internal use only. API stablility is not guaranteed!**
+    #[doc(hidden)]
+    pub struct CapturesRegexMatcher<'a, ActualT: ?Sized> {
+        regex: Regex,
+        groups: Vec<(CaptureGroup, GroupMatcher<'a>)>,
+        phantom: PhantomData<ActualT>,
+    }
+
+    impl<'a, ActualT: ?Sized> CapturesRegexMatcher<'a, ActualT> {
+        /// Factory only intended for use in the macro `captures_regex!`.
+        ///
+        /// **For internal use only. API stablility is not guaranteed!**
+        #[doc(hidden)]
+        pub fn new(
+            pattern: impl Deref<Target = str>,
+            groups: Vec<(CaptureGroup, GroupMatcher<'a>)>,
+        ) -> Self {
+            let regex = Regex::new(pattern.deref()).unwrap();
+            for (group, _) in &groups {
+                let exists = match group {
+                    CaptureGroup::Index(index) => *index < regex.captures_len(),
+                    CaptureGroup::Name(name) => regex.capture_names().any(|n| n == Some(*name)),
+                };
+                if !exists {
+                    panic!(
+                        "The regular expression {:#?} has no capture group {group}",
+                        regex.as_str()
+                    );
+                }
+            }
+            Self { regex, groups, phantom: Default::default() }
+        }
+
+        /// Factory only intended for use in the macro `captures_regex!`.
+        ///
+        /// **For internal use only. API stablility is not guaranteed!**
+        #[doc(hidden)]
+        pub fn with_numbered_groups(
+            pattern: impl Deref<Target = str>,
+            matchers: Vec<GroupMatcher<'a>>,
+        ) -> Self {
+            let groups = matchers
+                .into_iter()
+                .enumerate()
+                .map(|(index, matcher)| (CaptureGroup::Index(index + 1), matcher))
+                .collect();
+            Self::new(pattern, groups)
+        }
+    }
+
+    impl<'a, ActualT: AsRef<str> + Debug + ?Sized> Matcher for CapturesRegexMatcher<'a, ActualT> {
+        type ActualT = ActualT;
+
+        fn matches(&self, actual: &ActualT) -> MatcherResult {
+            let Some(captures) = self.regex.captures(actual.as_ref()) else {
+                return MatcherResult::NoMatch;
+            };
+            self.groups
+                .iter()
+                .all(|(group, matcher)| {
+                    group.text(&captures).map_or(false, |text| matcher.matches(&text).is_match())
+                })
+                .into()
+        }
+
+        fn explain_match(&self, actual: &ActualT) -> Description {
+            let Some(captures) = self.regex.captures(actual.as_ref()) else {
+                return format!(
+                    "which doesn't contain the regular expression {:#?}",
+                    self.regex.as_str()
+                )
+                .into();
+            };
+            let mismatches = self
+                .groups
+                .iter()
+                .filter_map(|(group, matcher)| match group.text(&captures) {
+                    None => Some(format!("capture group {group} doesn't participate in the match")),
+                    Some(text) if matcher.matches(&text).is_no_match() => Some(format!(
+                        "capture group {group} is {text:?}, {}",
+                        matcher.explain_match(&text)
+                    )),
+                    Some(_) => None,
+                })
+                .collect::<Vec<_>>();
+            if mismatches.is_empty() {
+                "whose capture groups all match".into()
+            } else if mismatches.len() == 1 {
+                let mismatches = mismatches.into_iter().collect::<Description>();
+                format!("where {mismatches}").into()
+            } else {
+                let mismatches = mismatches.into_iter().collect::<Description>();
+                format!("where:\n{}", mismatches.bullet_list().indent()).into()
+            }
+        }
+
+        fn describe(&self, matcher_result: MatcherResult) -> Description {
+            format!(
+                "{} the regular expression {:#?} with capture groups:\n{}",
+                if matcher_result.into() { "contains" } else { "doesn't contain" },
+                self.regex.as_str(),
+                self.groups
+                    .iter()
+                    .map(|(group, matcher)| {
+                        format!("{group}: {}", matcher.describe(MatcherResult::Match))
+                    })
+                    .collect::<Description>()
+                    .indent()
+            )
+            .into()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::internal::CapturesRegexMatcher;
+    use crate::matcher::{Matcher, MatcherResult};
+    use crate::prelude::*;
+    use indoc::indoc;
+
+    #[test]
+    fn captures_regex_matches_numbered_groups() -> Result<()> {
+        verify_that!("key=123", captures_regex!(r"(\w+)=(\d+)", [eq("key"), starts_with("1")]))
+    }
+
+    #[test]
+    fn captures_regex_matches_named_groups() -> Result<()> {
+        verify_that!(
+            "key=123",
+            captures_regex!(r"(?P<key>\w+)=(?P<value>\d+)", ["value" => eq("123"), "key" => eq("key")])
+        )
+    }
+
+    #[test]
+    fn captures_regex_matches_substring() -> Result<()> {
+        verify_that!("the key=123 here", captures_regex!(r"(\w+)=(\d+)", [eq("key"), eq("123")]))
+    }
+
+    #[test]
+    fn captures_regex_matches_owned_string() -> Result<()> {
+        verify_that!("key=123".to_string(), captures_regex!(r"(\w+)=", [eq("key")]))
+    }
+
+    #[test]
+    fn captures_regex_does_not_match_when_regex_does_not_match() -> Result<()> {
+        verify_that!("key:123", not(captures_regex!(r"(\w+)=(\d+)", [anything(), anything()])))
+    }
+
+    #[test]
+    fn captures_regex_does_not_match_when_group_does_not_match() -> Result<()> {
+        verify_that!("key=123", not(captures_regex!(r"(\w+)=(\d+)", [eq("key"), eq("124")])))
+    }
+
+    #[test]
+    fn captures_regex_does_not_match_when_group_does_not_participate() -> Result<()> {
+        verify_that!("key", not(captures_regex!(r"(\w+)(=\d+)?", [anything(), anything()])))
+    }
+
+    #[test]
+    #[should_panic]
+    fn captures_regex_panics_on_missing_numbered_group() {
+        let _ = verify_that!("key", captures_regex!(r"(\w+)", [anything(), anything()]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn captures_regex_panics_on_missing_named_group() {
+        let _ = verify_that!("key", captures_regex!(r"(?P<key>\w+)", ["value" => anything()]));
+    }
+
+    #[test]
+    fn captures_regex_explains_mismatched_numbered_group() -> Result<()> {
+        let result =
+            verify_that!("key=123", captures_regex!(r"(\w+)=(\d+)", [eq("key"), eq("124")]));
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(indoc!(
+                r#"
+                Value of: "key=123"
+                Expected: contains the regular expression "(\\w+)=(\\d+)" with capture groups:
+                  #1: is equal to "key"
+                  #2: is equal to "124"
+                Actual: "key=123",
+                  where capture group #2 is "123", which isn't equal to "124"
+                "#
+            ))))
+        )
+    }
+
+    #[test]
+    fn captures_regex_explains_all_mismatched_named_groups() -> Result<()> {
+        let result = verify_that!(
+            "key=123",
+            captures_regex!(r"(?P<key>\w+)=(?P<value>\d+)", ["key" => eq("id"), "value" => eq("124")])
+        );
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(indoc!(
+                r#"
+                Actual: "key=123",
+                  where:
+                    * capture group "key" is "key", which isn't equal to "id"
+                    * capture group "value" is "123", which isn't equal to "124"
+                "#
+            ))))
+        )
+    }
+
+    #[test]
+    fn captures_regex_explains_non_participating_group() -> Result<()> {
+        let matcher: CapturesRegexMatcher<str> =
+            captures_regex!(r"(\w+)(=\d+)?", [anything(), anything()]);
+
+        verify_that!(
+            matcher.explain_match("key"),
+            displays_as(eq("where capture group #2 doesn't participate in the match"))
+        )
+    }
+
+    #[test]
+    fn captures_regex_explains_regex_mismatch() -> Result<()> {
+        let matcher: CapturesRegexMatcher<str> =
+            captures_regex!(r"(\w+)=(\d+)", [anything(), anything()]);
+
+        verify_that!(
+            matcher.explain_match("key:123"),
+            displays_as(eq(r#"which doesn't contain the regular expression "(\\w+)=(\\d+)""#))
+        )
+    }
+
+    #[test]
+    fn captures_regex_describes_no_match() -> Result<()> {
+        let matcher: CapturesRegexMatcher<str> = captures_regex!(r"(\w+)", [eq("key")]);
+
+        verify_that!(
+            Matcher::describe(&matcher, MatcherResult::NoMatch),
+            displays_as(eq(indoc!(
+                r#"
+                doesn't contain the regular expression "(\\w+)" with capture groups:
+                  #1: is equal to "key""#
+            )
+            .trim_start()))
+        )
+    }
+}
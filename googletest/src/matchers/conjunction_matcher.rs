@@ -79,7 +79,6 @@ mod tests {
     fn and_true_true_matches() -> Result<()> {
         verify_that!(1, anything().and(anything()))
     }
-    #[cfg(do_not_compile)]
 
     #[test]
     fn and_true_false_does_not_match() -> Result<()> {
@@ -96,7 +95,6 @@ mod tests {
             ))))
         )
     }
-    #[cfg(do_not_compile)]
 
     #[test]
     fn and_false_true_does_not_match() -> Result<()> {
@@ -113,7 +111,6 @@ mod tests {
             ))))
         )
     }
-    #[cfg(do_not_compile)]
 
     #[test]
     fn and_false_false_does_not_match() -> Result<()> {
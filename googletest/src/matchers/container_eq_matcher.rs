@@ -183,9 +183,155 @@ fn build_explanation<T: Debug, U: Debug>(missing: Vec<T>, unexpected: Vec<U>) ->
     }
 }
 
+/// Matches a container containing the same elements as `expected`, in any
+/// order.
+///
+/// Unlike [`container_eq`], the order of the elements is irrelevant. Unlike
+/// [`superset_of`][crate::matchers::superset_of] and
+/// [`subset_of`][crate::matchers::subset_of], the number of times each element
+/// occurs is significant: the actual and expected containers must be equal as
+/// multisets.
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # fn should_pass() -> Result<()> {
+/// verify_that!(vec![3, 1, 2, 1], unordered_container_eq([1, 1, 2, 3]))?;  // Passes
+/// #     Ok(())
+/// # }
+/// # fn should_fail() -> Result<()> {
+/// verify_that!(vec![1, 2, 3], unordered_container_eq([1, 1, 2, 3]))?;  // Fails
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail().unwrap_err();
+/// ```
+///
+/// In case of a mismatch, the failure message counts the missing and
+/// unexpected occurrences of each element, e.g.:
+///
+/// ```text
+/// Actual: ["bar", "baz"],
+///   whose element counts differ: missing 2 × "foo", unexpected 1 × "bar"
+/// ```
+///
+/// As with [`container_eq`], the actual element type need only be comparable
+/// with the expected element type, so that a `Vec<String>` can be compared
+/// with an array of `&str`.
+///
+/// *Performance note*: This matcher uses a naive algorithm requiring time
+/// proportional to the product of the sizes of the expected and actual values.
+/// It should not be used on especially large containers.
+pub fn unordered_container_eq<
+    ActualElementT: PartialEq<ExpectedElementT> + PartialEq + Debug,
+    ActualContainerT: Debug + ?Sized,
+    ExpectedElementT: PartialEq + Debug,
+    ExpectedContainerT: Debug,
+>(
+    expected: ExpectedContainerT,
+) -> impl Matcher<ActualT = ActualContainerT>
+where
+    for<'a> &'a ActualContainerT: IntoIterator<Item = &'a ActualElementT>,
+    for<'a> &'a ExpectedContainerT: IntoIterator<Item = &'a ExpectedElementT>,
+{
+    UnorderedContainerEqMatcher { expected, phantom: Default::default() }
+}
+
+struct UnorderedContainerEqMatcher<ActualContainerT: ?Sized, ExpectedContainerT> {
+    expected: ExpectedContainerT,
+    phantom: PhantomData<ActualContainerT>,
+}
+
+impl<ActualElementT, ActualContainerT, ExpectedElementT, ExpectedContainerT> Matcher
+    for UnorderedContainerEqMatcher<ActualContainerT, ExpectedContainerT>
+where
+    ActualElementT: PartialEq<ExpectedElementT> + PartialEq + Debug,
+    ActualContainerT: Debug + ?Sized,
+    ExpectedElementT: PartialEq + Debug,
+    ExpectedContainerT: Debug,
+    for<'a> &'a ActualContainerT: IntoIterator<Item = &'a ActualElementT>,
+    for<'a> &'a ExpectedContainerT: IntoIterator<Item = &'a ExpectedElementT>,
+{
+    type ActualT = ActualContainerT;
+
+    fn matches(&self, actual: &ActualContainerT) -> MatcherResult {
+        let (missing, unexpected) = self.get_missing_and_unexpected_items(actual);
+        (missing.is_empty() && unexpected.is_empty()).into()
+    }
+
+    fn explain_match(&self, actual: &ActualContainerT) -> Description {
+        let (missing, unexpected) = self.get_missing_and_unexpected_items(actual);
+        let differences = count_occurrences(missing)
+            .into_iter()
+            .map(|(item, count)| format!("missing {count} × {item:?}"))
+            .chain(
+                count_occurrences(unexpected)
+                    .into_iter()
+                    .map(|(item, count)| format!("unexpected {count} × {item:?}")),
+            )
+            .collect::<Vec<_>>();
+        if differences.is_empty() {
+            "which contains all the elements".into()
+        } else {
+            format!("whose element counts differ: {}", differences.join(", ")).into()
+        }
+    }
+
+    fn describe(&self, matcher_result: MatcherResult) -> Description {
+        match matcher_result {
+            MatcherResult::Match => {
+                format!("contains the same elements as {:?} in any order", self.expected).into()
+            }
+            MatcherResult::NoMatch => {
+                format!("doesn't contain the same elements as {:?} in any order", self.expected)
+                    .into()
+            }
+        }
+    }
+}
+
+impl<ActualElementT, ActualContainerT, ExpectedElementT, ExpectedContainerT>
+    UnorderedContainerEqMatcher<ActualContainerT, ExpectedContainerT>
+where
+    ActualElementT: PartialEq<ExpectedElementT>,
+    ActualContainerT: ?Sized,
+    for<'a> &'a ActualContainerT: IntoIterator<Item = &'a ActualElementT>,
+    for<'a> &'a ExpectedContainerT: IntoIterator<Item = &'a ExpectedElementT>,
+{
+    /// Pairs each actual item with a distinct equal expected item and returns
+    /// the expected and actual items which are left over.
+    fn get_missing_and_unexpected_items<'a>(
+        &'a self,
+        actual: &'a ActualContainerT,
+    ) -> (Vec<&'a ExpectedElementT>, Vec<&'a ActualElementT>) {
+        let mut missing: Vec<_> = self.expected.into_iter().collect();
+        let mut unexpected = vec![];
+        for actual_item in actual {
+            if let Some(index) = missing.iter().position(|&item| actual_item == item) {
+                missing.remove(index);
+            } else {
+                unexpected.push(actual_item);
+            }
+        }
+        (missing, unexpected)
+    }
+}
+
+/// Groups equal items together, preserving the order of their first occurrence.
+fn count_occurrences<T: PartialEq + ?Sized>(items: Vec<&T>) -> Vec<(&T, usize)> {
+    let mut counts: Vec<(&T, usize)> = vec![];
+    for item in items {
+        if let Some((_, count)) = counts.iter_mut().find(|(other, _)| *other == item) {
+            *count += 1;
+        } else {
+            counts.push((item, 1));
+        }
+    }
+    counts
+}
+
 #[cfg(test)]
 mod tests {
-    use super::container_eq;
+    use super::{container_eq, unordered_container_eq};
     use crate::matcher::{Matcher, MatcherResult};
     use crate::prelude::*;
     use indoc::indoc;
@@ -315,4 +461,59 @@ mod tests {
             displays_as(eq("which contains the unexpected element \"C\""))
         )
     }
+
+    #[test]
+    fn unordered_container_eq_matches_elements_in_any_order() -> Result<()> {
+        verify_that!(vec![3, 1, 2], unordered_container_eq([1, 2, 3]))
+    }
+
+    #[test]
+    fn unordered_container_eq_matches_duplicated_elements() -> Result<()> {
+        verify_that!(vec![1, 2, 1], unordered_container_eq([1, 1, 2]))
+    }
+
+    #[test]
+    fn unordered_container_eq_does_not_match_different_number_of_duplicates() -> Result<()> {
+        verify_that!(vec![1, 2, 2], not(unordered_container_eq([1, 1, 2])))
+    }
+
+    #[test]
+    fn unordered_container_eq_matches_owned_strings_with_string_references() -> Result<()> {
+        let vector = vec!["B".to_string(), "A".to_string()];
+        verify_that!(vector, unordered_container_eq(["A", "B"]))
+    }
+
+    #[test]
+    fn unordered_container_eq_counts_missing_and_unexpected_elements() -> Result<()> {
+        verify_that!(
+            unordered_container_eq(["foo", "foo", "baz"]).explain_match(&vec!["bar", "baz"]),
+            displays_as(eq(
+                r#"whose element counts differ: missing 2 × "foo", unexpected 1 × "bar""#
+            ))
+        )
+    }
+
+    #[test]
+    fn unordered_container_eq_counts_surplus_duplicates() -> Result<()> {
+        verify_that!(
+            unordered_container_eq([1, 2]).explain_match(&vec![2, 1, 2, 2]),
+            displays_as(eq("whose element counts differ: unexpected 2 × 2"))
+        )
+    }
+
+    #[test]
+    fn unordered_container_eq_full_error_message() -> Result<()> {
+        let result = verify_that!(vec![1, 3, 3], unordered_container_eq([3, 1, 2]));
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(indoc!(
+                "
+                    Value of: vec![1, 3, 3]
+                    Expected: contains the same elements as [3, 1, 2] in any order
+                    Actual: [1, 3, 3],
+                      whose element counts differ: missing 1 × 2, unexpected 1 × 3
+                "
+            ))))
+        )
+    }
 }
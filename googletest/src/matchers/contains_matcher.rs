@@ -22,7 +22,9 @@ use std::{fmt::Debug, marker::PhantomData};
 ///
 /// By default, this matches a container with any number of elements matched
 /// by `inner`. Use the method [`ContainsMatcher::times`] to constrain the
-/// matched containers to a specific number of matching elements.
+/// matched containers to a specific number of matching elements. To require
+/// that every element be matched by `inner`, use
+/// [`each`][crate::matchers::each] instead.
 ///
 /// ```
 /// # use googletest::prelude::*;
@@ -50,10 +50,14 @@ where
     }
 
     fn explain_match(&self, actual: &M1::ActualT) -> Description {
-        Description::new()
-            .nested(self.m1.explain_match(actual))
-            .text("and")
-            .nested(self.m2.explain_match(actual))
+        match (self.m1.matches(actual), self.m2.matches(actual)) {
+            (MatcherResult::Match, _) => self.m1.explain_match(actual),
+            (MatcherResult::NoMatch, MatcherResult::Match) => self.m2.explain_match(actual),
+            (MatcherResult::NoMatch, MatcherResult::NoMatch) => Description::new()
+                .nested(self.m1.explain_match(actual))
+                .text("and")
+                .nested(self.m2.explain_match(actual)),
+        }
     }
 
     fn describe(&self, matcher_result: MatcherResult) -> Description {
@@ -64,6 +68,8 @@ where
 
 #[cfg(test)]
 mod tests {
+    use super::DisjunctionMatcher;
+    use crate::matcher::{Matcher, MatcherResult};
     use crate::prelude::*;
     use indoc::indoc;
 
@@ -82,6 +88,46 @@ mod tests {
         verify_that!(1, not(anything()).or(anything()))
     }
 
+    #[test]
+    fn or_true_false_explains_matching_alternative() -> Result<()> {
+        let matcher = eq::<i32, _>(1).or(eq(2));
+
+        verify_that!(matcher.explain_match(&1), displays_as(eq("which is equal to 1")))
+    }
+
+    #[test]
+    fn or_false_true_explains_matching_alternative() -> Result<()> {
+        let matcher = eq::<i32, _>(1).or(eq(2));
+
+        verify_that!(matcher.explain_match(&2), displays_as(eq("which is equal to 2")))
+    }
+
+    #[test]
+    fn or_false_false_explains_both_alternatives() -> Result<()> {
+        let matcher: DisjunctionMatcher<StrMatcher<&str, _>, _> =
+            starts_with("a").or(ends_with("z"));
+
+        verify_that!(
+            matcher.explain_match(&"bcd"),
+            displays_as(eq(indoc!(
+                "
+                  which does not start with \"a\"
+                and
+                  which does not end with \"z\""
+            )))
+        )
+    }
+
+    #[test]
+    fn or_describes_both_alternatives() -> Result<()> {
+        let matcher = eq::<i32, _>(1).or(eq(2));
+
+        verify_that!(
+            matcher.describe(MatcherResult::Match),
+            displays_as(eq("is equal to 1, or is equal to 2"))
+        )
+    }
+
     #[test]
     fn or_false_false_does_not_match() -> Result<()> {
         let result = verify_that!(1, not(anything()).or(not(anything())));
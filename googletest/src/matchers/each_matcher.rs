@@ -61,6 +61,11 @@ use std::{fmt::Debug, marker::PhantomData};
 /// # }
 /// # should_pass().unwrap();
 /// ```
+///
+/// When more than one element does not match, the failure message lists each
+/// of them with its index. To only require that a certain number of elements
+/// match, use [`contains`][crate::matchers::contains] with
+/// [`times`][crate::matchers::ContainsMatcher::times] instead.
 pub fn each<ElementT: Debug, ActualT: Debug + ?Sized, MatcherT>(
     inner: MatcherT,
 ) -> impl Matcher<ActualT = ActualT>
@@ -233,6 +238,24 @@ mod tests {
             ))))
         )
     }
+
+    #[test]
+    fn each_shows_every_non_matching_element() -> Result<()> {
+        let result = verify_that!(vec![5, 1, 7, 2, 0], each(gt(3)));
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(indoc!(
+                "
+                Actual: [5, 1, 7, 2, 0],
+                  whose elements #1, #3, #4 don't match
+                    1, which is less than or equal to 3
+                    2, which is less than or equal to 3
+                    0, which is less than or equal to 3"
+            ))))
+        )
+    }
+
     #[test]
     fn each_shows_inner_explanation() -> Result<()> {
         let result = verify_that!(vec![vec![1, 2], vec![1]], each(each(eq(1))));
@@ -15,6 +15,7 @@
 use crate::{
     description::Description,
     matcher::{Matcher, MatcherResult},
+    matcher_support::count_elements::count_elements,
 };
 use std::{fmt::Debug, marker::PhantomData};
 
@@ -49,7 +50,6 @@ use std::{fmt::Debug, marker::PhantomData};
 /// # }
 /// # should_pass().unwrap();
 /// ```
-
 pub fn empty<T: Debug + ?Sized>() -> impl Matcher<ActualT = T>
 where
     for<'a> &'a T: IntoIterator,
@@ -74,13 +74,22 @@ where
     fn describe(&self, matcher_result: MatcherResult) -> Description {
         if matcher_result.into() { "is empty" } else { "isn't empty" }.into()
     }
+
+    fn explain_match(&self, actual: &T) -> Description {
+        match count_elements(actual) {
+            0 => "which is empty".into(),
+            actual_size => format!("which has length {actual_size}").into(),
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::empty;
+    use crate::matcher::Matcher;
     use crate::prelude::*;
-    use std::collections::HashSet;
+    use indoc::indoc;
+    use std::collections::{BTreeSet, HashMap, HashSet};
 
     #[test]
     fn empty_matcher_match_empty_vec() -> Result<()> {
@@ -105,4 +114,52 @@ mod tests {
         let value: HashSet<i32> = HashSet::new();
         verify_that!(value, empty())
     }
+
+    #[test]
+    fn empty_matcher_matches_empty_hash_map() -> Result<()> {
+        let value: HashMap<i32, i32> = HashMap::new();
+        verify_that!(value, empty())
+    }
+
+    #[test]
+    fn empty_matcher_does_not_match_non_empty_btree_set() -> Result<()> {
+        let value = BTreeSet::from([1]);
+        verify_that!(value, not(empty()))
+    }
+
+    #[test]
+    fn empty_matcher_matches_collection_only_iterable_by_reference() -> Result<()> {
+        #[derive(Debug)]
+        struct Collection(Vec<i32>);
+        impl<'a> IntoIterator for &'a Collection {
+            type Item = &'a i32;
+            type IntoIter = std::slice::Iter<'a, i32>;
+            fn into_iter(self) -> Self::IntoIter {
+                self.0.iter()
+            }
+        }
+
+        verify_that!(Collection(vec![]), empty())?;
+        verify_that!(Collection(vec![1]), not(empty()))
+    }
+
+    #[test]
+    fn empty_matcher_explains_length_of_non_empty_container() -> Result<()> {
+        verify_that!(empty().explain_match(&vec![1, 2, 3]), displays_as(eq("which has length 3")))
+    }
+
+    #[test]
+    fn empty_matcher_error_message() -> Result<()> {
+        let result = verify_that!(vec![1, 2], empty());
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(indoc!(
+                "
+                Value of: vec![1, 2]
+                Expected: is empty
+                Actual: [1, 2],
+                  which has length 2"
+            ))))
+        )
+    }
 }
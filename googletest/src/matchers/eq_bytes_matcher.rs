@@ -0,0 +1,135 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    description::Description,
+    matcher::{Matcher, MatcherResult},
+    matcher_support::summarize_diff::create_hex_diff,
+};
+use std::{fmt::Debug, marker::PhantomData};
+
+/// Matches a byte sequence equal to `expected`.
+///
+/// Both the actual value and `expected` may be any type which can be viewed as
+/// a byte slice, such as `&[u8]`, `[u8; N]` or `Vec<u8>`. They need not be of
+/// the same type.
+///
+/// Unlike [`eq`][crate::matchers::eq], the failure message contains an aligned
+/// hex and ASCII dump of both values in which the differing bytes are
+/// highlighted.
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # fn should_pass() -> Result<()> {
+/// verify_that!(b"\x01\x02".to_vec(), eq_bytes([1, 2]))?; // Passes
+/// #     Ok(())
+/// # }
+/// # fn should_fail() -> Result<()> {
+/// let bytes: &[u8] = b"key=value";
+/// verify_that!(bytes, eq_bytes(b"key=valve"))?; // Fails
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail().unwrap_err();
+/// ```
+pub fn eq_bytes<ActualT: AsRef<[u8]> + Debug + ?Sized, ExpectedT: AsRef<[u8]> + Debug>(
+    expected: ExpectedT,
+) -> impl Matcher<ActualT = ActualT> {
+    EqBytesMatcher { expected, phantom: Default::default() }
+}
+
+struct EqBytesMatcher<ActualT: ?Sized, ExpectedT> {
+    expected: ExpectedT,
+    phantom: PhantomData<ActualT>,
+}
+
+impl<ActualT: AsRef<[u8]> + Debug + ?Sized, ExpectedT: AsRef<[u8]> + Debug> Matcher
+    for EqBytesMatcher<ActualT, ExpectedT>
+{
+    type ActualT = ActualT;
+
+    fn matches(&self, actual: &ActualT) -> MatcherResult {
+        (actual.as_ref() == self.expected.as_ref()).into()
+    }
+
+    fn describe(&self, matcher_result: MatcherResult) -> Description {
+        match matcher_result {
+            MatcherResult::Match => format!("is equal to {:?}", self.expected).into(),
+            MatcherResult::NoMatch => format!("isn't equal to {:?}", self.expected).into(),
+        }
+    }
+
+    fn explain_match(&self, actual: &ActualT) -> Description {
+        format!(
+            "which {}{}",
+            self.describe(self.matches(actual)),
+            create_hex_diff(actual.as_ref(), self.expected.as_ref())
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::eq_bytes;
+    use crate::prelude::*;
+    use indoc::indoc;
+
+    #[test]
+    fn matches_byte_vec_with_byte_array() -> Result<()> {
+        verify_that!(b"abc".to_vec(), eq_bytes(b"abc"))
+    }
+
+    #[test]
+    fn does_not_match_byte_slice_with_other_bytes() -> Result<()> {
+        let actual: &[u8] = b"abc";
+        verify_that!(actual, not(eq_bytes(b"abd")))
+    }
+
+    #[test]
+    fn byte_vec_shows_hex_diff() -> Result<()> {
+        let result =
+            verify_that!(b"\x01\x02key=value".to_vec(), eq_bytes(b"\x01\x03key=valve".to_vec()));
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(indoc!(
+                "
+                Actual: [1, 2, 107, 101, 121, 61, 118, 97, 108, 117, 101],
+                  which isn't equal to [1, 3, 107, 101, 121, 61, 118, 97, 108, 118, 101]
+                  Difference(-actual / +expected):
+                  -00000000: 01 02 6b 65 79 3d 76 61  6c 75 65                |..key=value|
+                  +00000000: 01 03 6b 65 79 3d 76 61  6c 76 65                |..key=valve|
+                                ^^                       ^^                     ^       ^
+                "
+            ))))
+        )
+    }
+
+    #[test]
+    fn byte_slice_with_byte_array_shows_hex_diff() -> Result<()> {
+        let actual: &[u8] = b"abc";
+        let result = verify_that!(actual, eq_bytes(b"abd"));
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(indoc!(
+                "
+                Actual: [97, 98, 99],
+                  which isn't equal to [97, 98, 100]
+                  Difference(-actual / +expected):
+                  -00000000: 61 62 63                                         |abc|
+                  +00000000: 61 62 64                                         |abd|"
+            ))))
+        )
+    }
+}
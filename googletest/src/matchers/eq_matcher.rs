@@ -67,6 +67,9 @@ use std::{fmt::Debug, marker::PhantomData};
 /// verify_that(actual, eq(&expected))?; // Compiles
 /// ```
 ///
+/// To compare byte sequences with a hex dump of the differing bytes in the
+/// failure message, use [`eq_bytes`][crate::matchers::eq_bytes] instead.
+///
 /// When matching with string types (`&str` and `String`), one can set more
 /// options on how equality is checked through the
 /// [`StrMatcherConfigurator`][crate::matchers::str_matcher::StrMatcherConfigurator]
@@ -137,7 +140,6 @@ fn to_display_output(string: &str) -> Option<String> {
 mod tests {
     use super::eq;
     use crate::prelude::*;
-    use indoc::indoc;
 
     #[test]
     fn eq_matches_string_reference_with_string_reference() -> Result<()> {
@@ -149,4 +149,28 @@ mod tests {
             displays_as(eq("is an error which is equal to 1"))
         )
     }
+
+    #[test]
+    fn err_describe_does_not_match() -> Result<()> {
+        verify_that!(
+            err::<i32, i32>(eq(1)).describe(MatcherResult::NoMatch),
+            displays_as(eq("is a success or is an error containing a value which isn't equal to 1"))
+        )
+    }
+
+    #[test]
+    fn err_explain_match_with_ok() -> Result<()> {
+        verify_that!(
+            err(eq::<i32, _>(1)).explain_match(&Ok::<i32, i32>(1)),
+            displays_as(eq("which is a success"))
+        )
+    }
+
+    #[test]
+    fn err_explain_match_with_err_fail() -> Result<()> {
+        verify_that!(
+            err(eq(1)).explain_match(&Err::<i32, i32>(2)),
+            displays_as(eq("which is an error\n  which isn't equal to 1"))
+        )
+    }
 }
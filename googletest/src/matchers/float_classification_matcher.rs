@@ -0,0 +1,204 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    description::Description,
+    matcher::{Matcher, MatcherResult},
+};
+use num_traits::float::Float;
+use std::{fmt::Debug, marker::PhantomData, num::FpCategory};
+
+/// Matches a floating point value which is finite, i.e., neither infinite nor
+/// NaN.
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # fn should_pass() -> Result<()> {
+/// verify_that!(1.0f32, is_finite())?; // Passes
+/// verify_that!(vec![0.0, -1.5, f64::MIN], each(is_finite()))?; // Passes
+/// #     Ok(())
+/// # }
+/// # fn should_fail() -> Result<()> {
+/// verify_that!(vec![0.0, f64::INFINITY, f64::NAN], each(is_finite()))?; // Fails
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail().unwrap_err();
+/// ```
+pub fn is_finite<T: Float + Debug>() -> impl Matcher<ActualT = T> {
+    FloatClassificationMatcher::<T>::new(|actual| actual.is_finite(), "finite")
+}
+
+/// Matches a floating point value which is positive or negative infinity.
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # fn should_pass() -> Result<()> {
+/// verify_that!(f32::INFINITY, is_infinite())?; // Passes
+/// verify_that!(f64::NEG_INFINITY, is_infinite())?; // Passes
+/// #     Ok(())
+/// # }
+/// # fn should_fail() -> Result<()> {
+/// verify_that!(f64::NAN, is_infinite())?; // Fails
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail().unwrap_err();
+/// ```
+pub fn is_infinite<T: Float + Debug>() -> impl Matcher<ActualT = T> {
+    FloatClassificationMatcher::<T>::new(|actual| actual.is_infinite(), "infinite")
+}
+
+/// Matches a floating point value which is subnormal, i.e., non-zero and
+/// smaller in magnitude than the smallest normal value of its type.
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # fn should_pass() -> Result<()> {
+/// verify_that!(f64::MIN_POSITIVE / 2.0, is_subnormal())?; // Passes
+/// #     Ok(())
+/// # }
+/// # fn should_fail() -> Result<()> {
+/// verify_that!(0.0f32, is_subnormal())?; // Fails
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail().unwrap_err();
+/// ```
+pub fn is_subnormal<T: Float + Debug>() -> impl Matcher<ActualT = T> {
+    FloatClassificationMatcher::<T>::new(
+        |actual| actual.classify() == FpCategory::Subnormal,
+        "subnormal",
+    )
+}
+
+/// A matcher which matches a floating point value for which `predicate` holds,
+/// described as being of the class `class`.
+struct FloatClassificationMatcher<T> {
+    predicate: fn(&T) -> bool,
+    class: &'static str,
+    phantom: PhantomData<T>,
+}
+
+impl<T> FloatClassificationMatcher<T> {
+    fn new(predicate: fn(&T) -> bool, class: &'static str) -> Self {
+        Self { predicate, class, phantom: Default::default() }
+    }
+}
+
+impl<T: Float + Debug> Matcher for FloatClassificationMatcher<T> {
+    type ActualT = T;
+
+    fn matches(&self, actual: &T) -> MatcherResult {
+        (self.predicate)(actual).into()
+    }
+
+    fn describe(&self, matcher_result: MatcherResult) -> Description {
+        match matcher_result {
+            MatcherResult::Match => format!("is {}", self.class).into(),
+            MatcherResult::NoMatch => format!("isn't {}", self.class).into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_finite, is_infinite, is_subnormal};
+    use crate::prelude::*;
+    use indoc::indoc;
+
+    #[test]
+    fn is_finite_matches_f32_number() -> Result<()> {
+        verify_that!(1.5f32, is_finite())
+    }
+
+    #[test]
+    fn is_finite_matches_f64_subnormal_number() -> Result<()> {
+        verify_that!(f64::MIN_POSITIVE / 2.0, is_finite())
+    }
+
+    #[test]
+    fn is_finite_does_not_match_infinity() -> Result<()> {
+        verify_that!(f64::NEG_INFINITY, not(is_finite()))
+    }
+
+    #[test]
+    fn is_finite_does_not_match_nan() -> Result<()> {
+        verify_that!(f32::NAN, not(is_finite()))
+    }
+
+    #[test]
+    fn each_names_non_finite_element() -> Result<()> {
+        let result = verify_that!(vec![1.0f32, f32::INFINITY, 2.0], each(is_finite()));
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(indoc!(
+                "
+                Expected: only contains elements that is finite
+                Actual: [1.0, inf, 2.0],
+                  whose element #1 is inf, which isn't finite
+                "
+            ))))
+        )
+    }
+
+    #[test]
+    fn is_finite_composes_with_pointwise() -> Result<()> {
+        verify_that!(
+            vec![1.0, 2.0],
+            pointwise!(|e| is_finite().and(near(e, 0.1)), vec![1.05, 1.95])
+        )
+    }
+
+    #[test]
+    fn is_infinite_matches_f32_infinity() -> Result<()> {
+        verify_that!(f32::INFINITY, is_infinite())
+    }
+
+    #[test]
+    fn is_infinite_matches_f64_negative_infinity() -> Result<()> {
+        verify_that!(f64::NEG_INFINITY, is_infinite())
+    }
+
+    #[test]
+    fn is_infinite_does_not_match_max_value() -> Result<()> {
+        verify_that!(f64::MAX, not(is_infinite()))
+    }
+
+    #[test]
+    fn is_infinite_does_not_match_nan() -> Result<()> {
+        verify_that!(f32::NAN, not(is_infinite()))
+    }
+
+    #[test]
+    fn is_subnormal_matches_f32_subnormal_number() -> Result<()> {
+        verify_that!(f32::MIN_POSITIVE / 2.0, is_subnormal())
+    }
+
+    #[test]
+    fn is_subnormal_matches_f64_negative_subnormal_number() -> Result<()> {
+        verify_that!(-f64::MIN_POSITIVE / 4.0, is_subnormal())
+    }
+
+    #[test]
+    fn is_subnormal_does_not_match_smallest_normal_number() -> Result<()> {
+        verify_that!(f64::MIN_POSITIVE, not(is_subnormal()))
+    }
+
+    #[test]
+    fn is_subnormal_does_not_match_zero() -> Result<()> {
+        verify_that!(0.0f32, not(is_subnormal()))
+    }
+}
@@ -14,14 +14,17 @@
 
 use crate::description::Description;
 use crate::matcher::{Matcher, MatcherResult};
-use std::collections::HashMap;
+use crate::matcher_support::map::Map;
 use std::fmt::Debug;
-use std::hash::Hash;
 use std::marker::PhantomData;
 
-/// Matches a HashMap containing the given `key` whose value is matched by the
+/// Matches a map containing the given `key` whose value is matched by the
 /// matcher `inner`.
 ///
+/// The actual value can be a [`HashMap`][std::collections::HashMap] or a
+/// [`BTreeMap`][std::collections::BTreeMap]. The key, which has the key type of
+/// the map, is looked up with the `get` method of the map.
+///
 /// ```
 /// # use googletest::prelude::*;
 /// # use std::collections::HashMap;
@@ -62,25 +65,32 @@ use std::marker::PhantomData;
 /// However, `has_entry` will offer somewhat better diagnostic messages in the
 /// case of assertion failure. And it avoid the extra allocation hidden in the
 /// code above.
-pub fn has_entry<KeyT: Debug + Eq + Hash, ValueT: Debug, MatcherT: Matcher<ActualT = ValueT>>(
-    key: KeyT,
+pub fn has_entry<MapT: Debug + Map + ?Sized, MatcherT: Matcher<ActualT = MapT::Value>>(
+    key: MapT::Key,
     inner: MatcherT,
-) -> impl Matcher<ActualT = HashMap<KeyT, ValueT>> {
+) -> impl Matcher<ActualT = MapT>
+where
+    MapT::Key: Debug,
+    MapT::Value: Debug,
+{
     HasEntryMatcher { key, inner, phantom: Default::default() }
 }
 
-struct HasEntryMatcher<KeyT, ValueT, MatcherT> {
-    key: KeyT,
+struct HasEntryMatcher<MapT: Map + ?Sized, MatcherT> {
+    key: MapT::Key,
     inner: MatcherT,
-    phantom: PhantomData<ValueT>,
+    phantom: PhantomData<MapT>,
 }
 
-impl<KeyT: Debug + Eq + Hash, ValueT: Debug, MatcherT: Matcher<ActualT = ValueT>> Matcher
-    for HasEntryMatcher<KeyT, ValueT, MatcherT>
+impl<MapT: Debug + Map + ?Sized, MatcherT: Matcher<ActualT = MapT::Value>> Matcher
+    for HasEntryMatcher<MapT, MatcherT>
+where
+    MapT::Key: Debug,
+    MapT::Value: Debug,
 {
-    type ActualT = HashMap<KeyT, ValueT>;
+    type ActualT = MapT;
 
-    fn matches(&self, actual: &HashMap<KeyT, ValueT>) -> MatcherResult {
+    fn matches(&self, actual: &MapT) -> MatcherResult {
         if let Some(value) = actual.get(&self.key) {
             self.inner.matches(value)
         } else {
@@ -88,7 +98,7 @@ impl<KeyT: Debug + Eq + Hash, ValueT: Debug, MatcherT: Matcher<ActualT = ValueT>
         }
     }
 
-    fn explain_match(&self, actual: &HashMap<KeyT, ValueT>) -> Description {
+    fn explain_match(&self, actual: &MapT) -> Description {
         if let Some(value) = actual.get(&self.key) {
             format!(
                 "which contains key {:?}, but is mapped to value {:#?}, {}",
@@ -126,7 +136,7 @@ mod tests {
     use super::has_entry;
     use crate::prelude::*;
     use indoc::indoc;
-    use std::collections::HashMap;
+    use std::collections::{BTreeMap, HashMap};
 
     #[test]
     fn has_entry_does_not_match_empty_hash_map() -> Result<()> {
@@ -152,6 +162,18 @@ mod tests {
         verify_that!(value, not(has_entry(0, eq(0))))
     }
 
+    #[test]
+    fn has_entry_matches_btree_map_with_value() -> Result<()> {
+        let value = BTreeMap::from([(0, 0), (1, 2)]);
+        verify_that!(value, has_entry(1, eq(2)))
+    }
+
+    #[test]
+    fn has_entry_matches_map_with_owned_string_keys() -> Result<()> {
+        let value = HashMap::from([("host".to_string(), "localhost"), ("port".to_string(), "80")]);
+        verify_that!(value, has_entry("port".to_string(), eq("80")))
+    }
+
     #[test]
     fn has_entry_shows_correct_message_when_key_is_not_present() -> Result<()> {
         let result = verify_that!(HashMap::from([(0, 0)]), has_entry(1, eq(0)));
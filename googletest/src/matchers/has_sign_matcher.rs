@@ -0,0 +1,128 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    description::Description,
+    matcher::{Matcher, MatcherResult},
+};
+use num_traits::float::Float;
+use std::{fmt::Debug, marker::PhantomData};
+
+/// The sign of a floating point value, as matched by [`has_sign`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Sign {
+    /// The sign bit is clear, as for `0.0`, positive numbers, positive
+    /// infinity and NaN without the sign bit.
+    Positive,
+    /// The sign bit is set, as for `-0.0`, negative numbers, negative infinity
+    /// and NaN with the sign bit.
+    Negative,
+}
+
+/// Matches a floating point value whose sign bit is `sign`.
+///
+/// This inspects the sign bit only, so it also distinguishes `0.0` from `-0.0`
+/// and applies to infinities and NaN.
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # fn should_pass() -> Result<()> {
+/// verify_that!(1.0f32, has_sign(Sign::Positive))?; // Passes
+/// verify_that!(-0.0f64, has_sign(Sign::Negative))?; // Passes
+/// verify_that!(vec![-1.0, f64::NEG_INFINITY], each(has_sign(Sign::Negative)))?; // Passes
+/// #     Ok(())
+/// # }
+/// # fn should_fail() -> Result<()> {
+/// verify_that!(0.0f64, has_sign(Sign::Negative))?; // Fails
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail().unwrap_err();
+/// ```
+pub fn has_sign<T: Float + Debug>(sign: Sign) -> impl Matcher<ActualT = T> {
+    HasSignMatcher::<T> { sign, phantom: Default::default() }
+}
+
+struct HasSignMatcher<T> {
+    sign: Sign,
+    phantom: PhantomData<T>,
+}
+
+impl<T: Float + Debug> Matcher for HasSignMatcher<T> {
+    type ActualT = T;
+
+    fn matches(&self, actual: &T) -> MatcherResult {
+        match self.sign {
+            Sign::Positive => actual.is_sign_positive(),
+            Sign::Negative => actual.is_sign_negative(),
+        }
+        .into()
+    }
+
+    fn describe(&self, matcher_result: MatcherResult) -> Description {
+        let sign = match self.sign {
+            Sign::Positive => "positive",
+            Sign::Negative => "negative",
+        };
+        match matcher_result {
+            MatcherResult::Match => format!("has a {sign} sign").into(),
+            MatcherResult::NoMatch => format!("doesn't have a {sign} sign").into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{has_sign, Sign};
+    use crate::prelude::*;
+    use indoc::indoc;
+
+    #[test]
+    fn matches_positive_f32() -> Result<()> {
+        verify_that!(1.0f32, has_sign(Sign::Positive))
+    }
+
+    #[test]
+    fn matches_negative_f64() -> Result<()> {
+        verify_that!(-1.0f64, has_sign(Sign::Negative))
+    }
+
+    #[test]
+    fn distinguishes_signed_zeros() -> Result<()> {
+        verify_that!(-0.0f64, has_sign(Sign::Negative))?;
+        verify_that!(-0.0f64, not(has_sign(Sign::Positive)))?;
+        verify_that!(0.0f64, has_sign(Sign::Positive))
+    }
+
+    #[test]
+    fn matches_infinity() -> Result<()> {
+        verify_that!(f32::NEG_INFINITY, has_sign(Sign::Negative))
+    }
+
+    #[test]
+    fn has_sign_full_error_message() -> Result<()> {
+        let result = verify_that!(vec![-1.0, 2.0], each(has_sign(Sign::Negative)));
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(indoc!(
+                "
+                Expected: only contains elements that has a negative sign
+                Actual: [-1.0, 2.0],
+                  whose element #1 is 2.0, which doesn't have a negative sign
+                "
+            ))))
+        )
+    }
+}
@@ -48,7 +48,7 @@ use std::{fmt::Debug, marker::PhantomData};
 /// # should_fail_1().unwrap_err();
 /// # should_fail_2().unwrap_err();
 /// ```
-pub fn is_utf8_string<'a, ActualT: AsRef<[u8]> + Debug + 'a, InnerMatcherT>(
+pub fn is_utf8_string<ActualT: AsRef<[u8]> + Debug, InnerMatcherT>(
     inner: InnerMatcherT,
 ) -> impl Matcher<ActualT = ActualT>
 where
@@ -62,7 +62,7 @@ struct IsEncodedStringMatcher<ActualT, InnerMatcherT> {
     phantom: PhantomData<ActualT>,
 }
 
-impl<'a, ActualT: AsRef<[u8]> + Debug + 'a, InnerMatcherT> Matcher
+impl<ActualT: AsRef<[u8]> + Debug, InnerMatcherT> Matcher
     for IsEncodedStringMatcher<ActualT, InnerMatcherT>
 where
     InnerMatcherT: Matcher<ActualT = String>,
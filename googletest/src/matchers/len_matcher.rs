@@ -176,6 +176,21 @@ mod tests {
         verify_that!(value, len(eq(3)))
     }
 
+    #[test]
+    fn len_matcher_match_collection_only_iterable_by_reference() -> Result<()> {
+        #[derive(Debug)]
+        struct Collection(Vec<i32>);
+        impl<'a> IntoIterator for &'a Collection {
+            type Item = &'a i32;
+            type IntoIter = std::slice::Iter<'a, i32>;
+            fn into_iter(self) -> Self::IntoIter {
+                self.0.iter()
+            }
+        }
+
+        verify_that!(Collection(vec![1, 2, 3, 4]), len(gt(3)))
+    }
+
     #[test]
     fn len_matcher_explain_match() -> Result<()> {
         struct TestMatcher<T>(PhantomData<T>);
@@ -0,0 +1,214 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::description::Description;
+use crate::matcher::{Matcher, MatcherResult};
+use crate::matcher_support::map::Map;
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+/// Matches a map whose keys, collected into a `Vec`, are matched by the
+/// container matcher `inner`.
+///
+/// The actual value can be a [`HashMap`][std::collections::HashMap] or a
+/// [`BTreeMap`][std::collections::BTreeMap]. The keys are passed to `inner` in
+/// the iteration order of the map, so only use order-sensitive matchers such as
+/// [`elements_are!`][crate::matchers::elements_are] with ordered maps.
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # use std::collections::BTreeMap;
+/// # fn should_pass() -> Result<()> {
+/// let value = BTreeMap::from([("host", "localhost"), ("port", "80")]);
+/// verify_that!(value, keys(contains(eq("port"))))?;  // Passes
+/// verify_that!(value, keys(elements_are![eq("host"), eq("port")]))?;  // Passes
+/// #     Ok(())
+/// # }
+/// # fn should_fail() -> Result<()> {
+/// # let value = BTreeMap::from([("host", "localhost"), ("port", "80")]);
+/// verify_that!(value, keys(contains(eq("user"))))?;  // Fails
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail().unwrap_err();
+/// ```
+pub fn keys<MapT: Debug + Map + ?Sized, MatcherT: Matcher<ActualT = Vec<MapT::Key>>>(
+    inner: MatcherT,
+) -> impl Matcher<ActualT = MapT>
+where
+    MapT::Key: Debug + Clone,
+{
+    MapProjectionMatcher { inner, projection: |key, _| key, name: "keys", phantom: PhantomData }
+}
+
+/// Matches a map whose values, collected into a `Vec`, are matched by the
+/// container matcher `inner`.
+///
+/// As with [`keys`], the values are passed to `inner` in the iteration order of
+/// the map.
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # use std::collections::HashMap;
+/// # fn should_pass() -> Result<()> {
+/// let value = HashMap::from([("min_replicas", 2), ("max_replicas", 5)]);
+/// verify_that!(value, values(each(gt(0))))?;  // Passes
+/// #     Ok(())
+/// # }
+/// # fn should_fail() -> Result<()> {
+/// # let value = HashMap::from([("min_replicas", 2), ("max_replicas", 5)]);
+/// verify_that!(value, values(contains(eq(3))))?;  // Fails
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail().unwrap_err();
+/// ```
+pub fn values<MapT: Debug + Map + ?Sized, MatcherT: Matcher<ActualT = Vec<MapT::Value>>>(
+    inner: MatcherT,
+) -> impl Matcher<ActualT = MapT>
+where
+    MapT::Value: Debug + Clone,
+{
+    MapProjectionMatcher {
+        inner,
+        projection: |_, value| value,
+        name: "values",
+        phantom: PhantomData,
+    }
+}
+
+/// A matcher which matches a map whose entries, each mapped through
+/// `projection` and collected into a `Vec`, are matched by `inner`.
+struct MapProjectionMatcher<MapT: Map + ?Sized, ProjectedT, MatcherT> {
+    inner: MatcherT,
+    projection: for<'a> fn(&'a MapT::Key, &'a MapT::Value) -> &'a ProjectedT,
+    /// What the projected parts of the entries are called in descriptions.
+    name: &'static str,
+    phantom: PhantomData<MapT>,
+}
+
+impl<MapT: Map + ?Sized, ProjectedT: Clone, MatcherT>
+    MapProjectionMatcher<MapT, ProjectedT, MatcherT>
+{
+    fn project(&self, actual: &MapT) -> Vec<ProjectedT> {
+        actual.entries().map(|(key, value)| (self.projection)(key, value).clone()).collect()
+    }
+}
+
+impl<
+        MapT: Debug + Map + ?Sized,
+        ProjectedT: Debug + Clone,
+        MatcherT: Matcher<ActualT = Vec<ProjectedT>>,
+    > Matcher for MapProjectionMatcher<MapT, ProjectedT, MatcherT>
+{
+    type ActualT = MapT;
+
+    fn matches(&self, actual: &MapT) -> MatcherResult {
+        self.inner.matches(&self.project(actual))
+    }
+
+    fn explain_match(&self, actual: &MapT) -> Description {
+        let projected = self.project(actual);
+        format!("whose {} are {projected:?}, {}", self.name, self.inner.explain_match(&projected))
+            .into()
+    }
+
+    fn describe(&self, matcher_result: MatcherResult) -> Description {
+        format!("has {}, which {}", self.name, self.inner.describe(matcher_result)).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{keys, values};
+    use crate::prelude::*;
+    use indoc::indoc;
+    use std::collections::{BTreeMap, HashMap};
+
+    #[test]
+    fn keys_matches_hash_map_containing_key() -> Result<()> {
+        let value = HashMap::from([(1, "one"), (2, "two")]);
+        verify_that!(value, keys(contains(eq(2))))
+    }
+
+    #[test]
+    fn keys_matches_btree_map_with_keys_in_order() -> Result<()> {
+        let value = BTreeMap::from([(2, "two"), (1, "one")]);
+        verify_that!(value, keys(elements_are![eq(1), eq(2)]))
+    }
+
+    #[test]
+    fn keys_matches_owned_string_keys() -> Result<()> {
+        let value = HashMap::from([("host".to_string(), 1), ("port".to_string(), 2)]);
+        verify_that!(value, keys(unordered_elements_are![eq("port"), eq("host")]))
+    }
+
+    #[test]
+    fn keys_does_not_match_map_without_key() -> Result<()> {
+        let value = HashMap::from([(1, "one")]);
+        verify_that!(value, not(keys(contains(eq(2)))))
+    }
+
+    #[test]
+    fn keys_shows_correct_message_on_mismatch() -> Result<()> {
+        let result = verify_that!(BTreeMap::from([(1, "one"), (3, "three")]), keys(each(lt(3))));
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(indoc!(
+                r#"
+                Value of: BTreeMap::from([(1, "one"), (3, "three")])
+                Expected: has keys, which only contains elements that is less than 3
+                Actual: {1: "one", 3: "three"},
+                  whose keys are [1, 3], whose element #1 is 3, which is greater than or equal to 3
+                "#
+            ))))
+        )
+    }
+
+    #[test]
+    fn values_matches_hash_map_containing_value() -> Result<()> {
+        let value = HashMap::from([(1, "one"), (2, "two")]);
+        verify_that!(value, values(contains(eq("two"))))
+    }
+
+    #[test]
+    fn values_matches_btree_map_with_values_in_key_order() -> Result<()> {
+        let value = BTreeMap::from([(2, "two"), (1, "one")]);
+        verify_that!(value, values(elements_are![eq("one"), eq("two")]))
+    }
+
+    #[test]
+    fn values_does_not_match_map_without_value() -> Result<()> {
+        let value = HashMap::from([(1, "one")]);
+        verify_that!(value, not(values(contains(eq("two")))))
+    }
+
+    #[test]
+    fn values_shows_correct_message_on_mismatch() -> Result<()> {
+        let result = verify_that!(BTreeMap::from([("a", 1), ("b", 0)]), values(each(gt(0))));
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(indoc!(
+                r#"
+                Value of: BTreeMap::from([("a", 1), ("b", 0)])
+                Expected: has values, which only contains elements that is greater than 0
+                Actual: {"a": 1, "b": 0},
+                  whose values are [1, 0], whose element #1 is 0, which is less than or equal to 0
+                "#
+            ))))
+        )
+    }
+}
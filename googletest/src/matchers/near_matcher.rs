@@ -117,7 +117,86 @@ pub fn near<T: Debug + Float + Copy>(expected: T, max_abs_error: T) -> NearMatch
     if max_abs_error < T::zero() {
         panic!("max_abs_error must be non-negative");
     }
-    NearMatcher { expected, max_abs_error, nans_are_equal: false }
+    NearMatcher {
+        expected,
+        tolerance: Tolerance::AbsoluteError(max_abs_error),
+        nans_are_equal: false,
+    }
+}
+
+/// Matches a value within `max_ulps` units in the last place (ULPs) of
+/// `expected`.
+///
+/// The distance between two floating point numbers in ULPs is the number of
+/// representable values of type `T` between them. Unlike [`near`], the
+/// resulting tolerance scales with the magnitude of `expected`, and it does
+/// not require choosing an absolute error bound up front.
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # fn should_pass() -> Result<()> {
+/// verify_that!(1.0f64 + f64::EPSILON, near_ulps(1.0f64, 1))?; // Passes
+/// verify_that!(1.0e10f64 + 1.0e10 * f64::EPSILON, near_ulps(1.0e10f64, 1))?; // Passes
+/// #     Ok(())
+/// # }
+/// # fn should_fail() -> Result<()> {
+/// verify_that!(1.0f64 + 4.0 * f64::EPSILON, near_ulps(1.0f64, 3))?; // Fails
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail().unwrap_err();
+/// ```
+///
+/// Positive and negative zero are zero ULPs apart, and the smallest positive
+/// and negative subnormal numbers are two ULPs apart. An infinity is zero ULPs
+/// from itself and one ULP from the largest finite value of the same sign. As
+/// with [`near`], `NaN` matches nothing unless
+/// [`NearMatcher::nans_are_equal`] is set.
+pub fn near_ulps<T: Debug + Float + Copy>(expected: T, max_ulps: u64) -> NearMatcher<T> {
+    NearMatcher { expected, tolerance: Tolerance::Ulps(max_ulps), nans_are_equal: false }
+}
+
+/// Matches a value whose distance to `expected` is at most
+/// `max_relative_error` times the magnitude of `expected`.
+///
+/// This is useful when the expected values span many orders of magnitude, so
+/// that no single absolute tolerance as accepted by [`near`] fits all of them.
+///
+/// The value `max_relative_error` may not be NaN and must be non-negative. The
+/// matcher panics on construction otherwise.
+///
+/// ```
+/// # use googletest::prelude::*;
+/// # fn should_pass() -> Result<()> {
+/// verify_that!(1.001, near_relative(1.0, 0.01))?; // Passes
+/// verify_that!(1001.0, near_relative(1000.0, 0.01))?; // Passes
+/// #     Ok(())
+/// # }
+/// # fn should_fail() -> Result<()> {
+/// verify_that!(1011.0, near_relative(1000.0, 0.01))?; // Fails
+/// #     Ok(())
+/// # }
+/// # should_pass().unwrap();
+/// # should_fail().unwrap_err();
+/// ```
+///
+/// Since the tolerance is proportional to `expected`, only zero itself is
+/// within any relative error of zero.
+pub fn near_relative<T: Debug + Float + Copy>(
+    expected: T,
+    max_relative_error: T,
+) -> NearMatcher<T> {
+    if max_relative_error.is_nan() {
+        panic!("max_relative_error must not be NaN");
+    }
+    if max_relative_error < T::zero() {
+        panic!("max_relative_error must be non-negative");
+    }
+    NearMatcher {
+        expected,
+        tolerance: Tolerance::RelativeError(max_relative_error),
+        nans_are_equal: false,
+    }
 }
 
 /// Matches a value approximately equal to `expected`.
@@ -134,17 +213,29 @@ pub fn approx_eq<T: Debug + Float + FloatConst + Copy>(expected: T) -> NearMatch
     let five_bits_of_mantissa = (T::one() + T::one()).powi(5);
     let abs_tolerance = five_bits_of_mantissa * T::epsilon();
     let max_abs_error = T::max(expected.abs() * abs_tolerance, abs_tolerance);
-    NearMatcher { expected, max_abs_error, nans_are_equal: false }
+    NearMatcher {
+        expected,
+        tolerance: Tolerance::AbsoluteError(max_abs_error),
+        nans_are_equal: false,
+    }
 }
 
 /// A matcher which matches floating-point numbers approximately equal to its
 /// expected value.
 pub struct NearMatcher<T: Debug> {
     expected: T,
-    max_abs_error: T,
+    tolerance: Tolerance<T>,
     nans_are_equal: bool,
 }
 
+/// The way in which a [`NearMatcher`] bounds the distance between the actual
+/// and the expected value.
+enum Tolerance<T> {
+    AbsoluteError(T),
+    Ulps(u64),
+    RelativeError(T),
+}
+
 impl<T: Debug> NearMatcher<T> {
     /// Configures this instance to treat two NaNs as equal.
     ///
@@ -174,29 +265,97 @@ impl<T: Debug + Float> Matcher for NearMatcher<T> {
             return MatcherResult::Match;
         }
 
-        let delta = *actual - self.expected;
-        if delta >= -self.max_abs_error && delta <= self.max_abs_error {
-            MatcherResult::Match
-        } else {
-            MatcherResult::NoMatch
+        match self.tolerance {
+            Tolerance::AbsoluteError(max_abs_error) => {
+                let delta = *actual - self.expected;
+                (delta >= -max_abs_error && delta <= max_abs_error).into()
+            }
+            Tolerance::Ulps(max_ulps) => ulps_between(*actual, self.expected)
+                .map(|ulps| ulps <= max_ulps as u128)
+                .unwrap_or(false)
+                .into(),
+            Tolerance::RelativeError(max_relative_error) => {
+                let delta = *actual - self.expected;
+                (delta.abs() <= max_relative_error * self.expected.abs()).into()
+            }
         }
     }
 
     fn describe(&self, matcher_result: MatcherResult) -> Description {
-        match matcher_result {
-            MatcherResult::Match => {
-                format!("is within {:?} of {:?}", self.max_abs_error, self.expected).into()
+        let within = match matcher_result {
+            MatcherResult::Match => "is within",
+            MatcherResult::NoMatch => "isn't within",
+        };
+        match self.tolerance {
+            Tolerance::AbsoluteError(max_abs_error) => {
+                format!("{within} {:?} of {:?}", max_abs_error, self.expected).into()
             }
-            MatcherResult::NoMatch => {
-                format!("isn't within {:?} of {:?}", self.max_abs_error, self.expected).into()
+            Tolerance::Ulps(max_ulps) => {
+                let unit = ulps_unit(max_ulps as u128);
+                format!("{within} {max_ulps} {unit} of {:?}", self.expected).into()
             }
+            Tolerance::RelativeError(max_relative_error) => format!(
+                "{within} a relative error of {:?} of {:?}",
+                max_relative_error, self.expected
+            )
+            .into(),
         }
     }
+
+    fn explain_match(&self, actual: &T) -> Description {
+        let explanation = format!("which {}", self.describe(self.matches(actual)));
+        match (&self.tolerance, ulps_between(*actual, self.expected)) {
+            (Tolerance::Ulps(_), Some(ulps)) => {
+                format!("{explanation} ({ulps} {} away)", ulps_unit(ulps)).into()
+            }
+            _ => explanation.into(),
+        }
+    }
+}
+
+/// Returns the unit of a distance of `count` ULPs, in singular or plural.
+fn ulps_unit(count: u128) -> &'static str {
+    if count == 1 { "ULP" } else { "ULPs" }
+}
+
+/// Returns the number of representable values of type `T` between `a` and `b`,
+/// or `None` if either of them is NaN.
+///
+/// This maps each value onto its position in the ordered sequence of all
+/// representable values, so that adjacent floating point numbers have adjacent
+/// positions, and takes the difference.
+fn ulps_between<T: Float>(a: T, b: T) -> Option<u128> {
+    if a.is_nan() || b.is_nan() {
+        return None;
+    }
+    Some(ulp_position(a).abs_diff(ulp_position(b)))
+}
+
+fn ulp_position<T: Float>(value: T) -> i128 {
+    // The significand of epsilon is exactly the implicit leading bit, so its
+    // number of trailing zeros is the number of explicit significand bits.
+    let (epsilon_significand, _, _) = T::epsilon().integer_decode();
+    let significand_bits = epsilon_significand.trailing_zeros();
+    let (_, min_normal_exponent, _) = T::min_positive_value().integer_decode();
+
+    let (significand, exponent, sign) = value.integer_decode();
+    let significand = significand as i128;
+    let magnitude = if exponent >= min_normal_exponent {
+        // Normal numbers and infinities: the biased exponent selects a block of
+        // 2^significand_bits consecutive positions.
+        let biased_exponent = (exponent - min_normal_exponent) as i128 + 1;
+        (biased_exponent << significand_bits) + (significand - (1 << significand_bits))
+    } else {
+        // Subnormal numbers and zero are reported with an exponent below the
+        // minimum normal exponent and a correspondingly scaled significand.
+        significand >> (min_normal_exponent - exponent)
+    };
+    if sign < 0 { -magnitude } else { magnitude }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{approx_eq, near};
+    use super::{approx_eq, near, near_relative, near_ulps};
     use crate::matcher::{Matcher, MatcherResult};
     use crate::prelude::*;
 
@@ -347,4 +506,137 @@ mod tests {
     fn approx_eq_does_not_match_distant_number() -> Result<()> {
         verify_that!(0.0f64, not(approx_eq(1.0f64)))
     }
+
+    #[test]
+    fn near_ulps_matches_equal_number() -> Result<()> {
+        verify_that!(1.0f64, near_ulps(1.0f64, 0))
+    }
+
+    #[test]
+    fn near_ulps_matches_adjacent_f64_number() -> Result<()> {
+        verify_that!(1.0f64 + f64::EPSILON, near_ulps(1.0f64, 1))
+    }
+
+    #[test]
+    fn near_ulps_does_not_match_number_too_many_ulps_away() -> Result<()> {
+        verify_that!(1.0f64 + 2.0 * f64::EPSILON, not(near_ulps(1.0f64, 1)))
+    }
+
+    #[test]
+    fn near_ulps_matches_adjacent_f32_number() -> Result<()> {
+        verify_that!(1.0f32 + f32::EPSILON, near_ulps(1.0f32, 1))
+    }
+
+    #[test]
+    fn near_ulps_tolerance_scales_with_magnitude() -> Result<()> {
+        verify_that!(1.0e20f64 + 1.0e20 * f64::EPSILON, near_ulps(1.0e20f64, 1))?;
+        verify_that!(1.0e-20f64 + 1.0e-20 * f64::EPSILON, near_ulps(1.0e-20f64, 1))
+    }
+
+    #[test]
+    fn near_ulps_counts_across_exponent_boundary() -> Result<()> {
+        verify_that!(1.0f64 - f64::EPSILON / 2.0, near_ulps(1.0f64 + f64::EPSILON, 2))?;
+        verify_that!(1.0f64 - f64::EPSILON / 2.0, not(near_ulps(1.0f64 + f64::EPSILON, 1)))
+    }
+
+    #[test]
+    fn near_ulps_treats_zeros_as_equal() -> Result<()> {
+        verify_that!(-0.0f64, near_ulps(0.0f64, 0))
+    }
+
+    #[test]
+    fn near_ulps_counts_across_zero() -> Result<()> {
+        let smallest_subnormal = f64::from_bits(1);
+
+        verify_that!(-smallest_subnormal, near_ulps(smallest_subnormal, 2))?;
+        verify_that!(-smallest_subnormal, not(near_ulps(smallest_subnormal, 1)))
+    }
+
+    #[test]
+    fn near_ulps_counts_between_subnormal_and_normal_numbers() -> Result<()> {
+        let largest_subnormal = f32::from_bits(0x007f_ffff);
+
+        verify_that!(largest_subnormal, near_ulps(f32::MIN_POSITIVE, 1))?;
+        verify_that!(largest_subnormal, not(near_ulps(f32::MIN_POSITIVE, 0)))
+    }
+
+    #[test]
+    fn near_ulps_treats_inf_as_adjacent_to_max() -> Result<()> {
+        verify_that!(f64::INFINITY, near_ulps(f64::INFINITY, 0))?;
+        verify_that!(f64::MAX, near_ulps(f64::INFINITY, 1))?;
+        verify_that!(f64::MAX, not(near_ulps(f64::INFINITY, 0)))
+    }
+
+    #[test]
+    fn near_ulps_does_not_match_nan_by_default() -> Result<()> {
+        verify_that!(f64::NAN, not(near_ulps(f64::NAN, u64::MAX)))
+    }
+
+    #[test]
+    fn near_ulps_matches_nan_if_nans_are_equal() -> Result<()> {
+        verify_that!(f64::NAN, near_ulps(f64::NAN, 0).nans_are_equal())
+    }
+
+    #[test]
+    fn near_ulps_describes_itself() -> Result<()> {
+        verify_that!(
+            near_ulps(1.0f64, 4).describe(MatcherResult::Match),
+            displays_as(eq("is within 4 ULPs of 1.0"))
+        )
+    }
+
+    #[test]
+    fn near_ulps_explains_distance_in_ulps() -> Result<()> {
+        verify_that!(
+            near_ulps(1.0f64, 1).explain_match(&(1.0 + 3.0 * f64::EPSILON)),
+            displays_as(eq("which isn't within 1 ULP of 1.0 (3 ULPs away)"))
+        )
+    }
+
+    #[test]
+    fn near_ulps_explains_distance_of_one_ulp_in_singular() -> Result<()> {
+        verify_that!(
+            near_ulps(1.0f64, 0).explain_match(&(1.0 + f64::EPSILON)),
+            displays_as(eq("which isn't within 0 ULPs of 1.0 (1 ULP away)"))
+        )
+    }
+
+    #[test]
+    fn near_relative_matches_value_within_relative_error() -> Result<()> {
+        verify_that!(1.001f64, near_relative(1.0f64, 0.01))?;
+        verify_that!(1001.0f64, near_relative(1000.0f64, 0.01))?;
+        verify_that!(-999.0f64, near_relative(-1000.0f64, 0.01))
+    }
+
+    #[test]
+    fn near_relative_does_not_match_value_outside_relative_error() -> Result<()> {
+        verify_that!(1.02f64, not(near_relative(1.0f64, 0.01)))?;
+        verify_that!(1011.0f64, not(near_relative(1000.0f64, 0.01)))
+    }
+
+    #[test]
+    fn near_relative_only_matches_zero_when_expecting_zero() -> Result<()> {
+        verify_that!(0.0f64, near_relative(0.0f64, 0.5))?;
+        verify_that!(f64::MIN_POSITIVE, not(near_relative(0.0f64, 0.5)))
+    }
+
+    #[test]
+    fn near_relative_describes_itself() -> Result<()> {
+        verify_that!(
+            near_relative(1000.0f64, 0.01).describe(MatcherResult::NoMatch),
+            displays_as(eq("isn't within a relative error of 0.01 of 1000.0"))
+        )
+    }
+
+    #[::core::prelude::v1::test]
+    #[should_panic]
+    fn near_relative_panics_if_max_relative_error_is_nan() {
+        near_relative(0.0, f64::NAN);
+    }
+
+    #[::core::prelude::v1::test]
+    #[should_panic]
+    fn near_relative_panics_if_max_relative_error_is_negative() {
+        near_relative(0.0, -1.0);
+    }
 }
@@ -60,6 +60,7 @@ mod tests {
     use super::none;
     use crate::matcher::{Matcher, MatcherResult};
     use crate::prelude::*;
+    use indoc::indoc;
 
     #[test]
     fn none_matches_option_with_none() -> Result<()> {
@@ -78,4 +79,21 @@ mod tests {
 
         verify_that!(result, eq(MatcherResult::NoMatch))
     }
+
+    #[test]
+    fn none_full_error_message() -> Result<()> {
+        let result = verify_that!(Some(2), none());
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(indoc!(
+                "
+                    Value of: Some(2)
+                    Expected: is none
+                    Actual: Some(2),
+                      which is some(_)
+                "
+            ))))
+        )
+    }
 }
@@ -151,4 +151,28 @@ mod tests {
             displays_as(eq("is a success containing a value, which is equal to 1"))
         )
     }
+
+    #[test]
+    fn ok_describe_does_not_match() -> Result<()> {
+        verify_that!(
+            ok::<i32, i32>(eq(1)).describe(MatcherResult::NoMatch),
+            displays_as(eq("is an error or a success containing a value, which isn't equal to 1"))
+        )
+    }
+
+    #[test]
+    fn ok_explain_match_with_err() -> Result<()> {
+        verify_that!(
+            ok(eq::<i32, _>(1)).explain_match(&Err::<i32, i32>(1)),
+            displays_as(eq("which is an error"))
+        )
+    }
+
+    #[test]
+    fn ok_explain_match_with_ok_fail() -> Result<()> {
+        verify_that!(
+            ok(eq(1)).explain_match(&Ok::<i32, i32>(2)),
+            displays_as(eq("which is a success\n  which isn't equal to 1"))
+        )
+    }
 }
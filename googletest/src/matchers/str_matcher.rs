@@ -911,7 +911,6 @@ mod tests {
         )
     }
 
-    #[cfg(do_not_compile)]
     #[test]
     fn describes_itself_with_count_number() -> Result<()> {
         let matcher: StrMatcher<&str, _> = contains_substring("A string").times(gt(2));
@@ -957,7 +956,6 @@ mod tests {
         )
     }
 
-    #[cfg(do_not_compile)]
     #[test]
     fn match_explanation_contains_diff_of_strings_if_more_than_one_line() -> Result<()> {
         let result = verify_that!(
@@ -989,7 +987,6 @@ mod tests {
         )
     }
 
-    #[cfg(do_not_compile)]
     #[test]
     fn match_explanation_for_starts_with_ignores_trailing_lines_in_actual_string() -> Result<()> {
         let result = verify_that!(
@@ -1023,7 +1020,6 @@ mod tests {
         )
     }
 
-    #[cfg(do_not_compile)]
     #[test]
     fn match_explanation_for_starts_with_includes_both_versions_of_differing_last_line()
     -> Result<()> {
@@ -1055,7 +1051,6 @@ mod tests {
         )
     }
 
-    #[cfg(do_not_compile)]
     #[test]
     fn match_explanation_for_ends_with_ignores_leading_lines_in_actual_string() -> Result<()> {
         let result = verify_that!(
@@ -1090,7 +1085,6 @@ mod tests {
         )
     }
 
-    #[cfg(do_not_compile)]
     #[test]
     fn match_explanation_for_contains_substring_ignores_outer_lines_in_actual_string() -> Result<()>
     {
@@ -1128,7 +1122,6 @@ mod tests {
         )
     }
 
-    #[cfg(do_not_compile)]
     #[test]
     fn match_explanation_for_contains_substring_shows_diff_when_first_and_last_line_are_incomplete()
     -> Result<()> {
@@ -1169,7 +1162,6 @@ mod tests {
         )
     }
 
-    #[cfg(do_not_compile)]
     #[test]
     fn match_explanation_for_eq_does_not_ignore_trailing_lines_in_actual_string() -> Result<()> {
         let result = verify_that!(
@@ -1203,7 +1195,6 @@ mod tests {
         )
     }
 
-    #[cfg(do_not_compile)]
     #[test]
     fn match_explanation_does_not_show_diff_if_actual_value_is_single_line() -> Result<()> {
         let result = verify_that!(
@@ -1222,7 +1213,6 @@ mod tests {
         )
     }
 
-    #[cfg(do_not_compile)]
     #[test]
     fn match_explanation_does_not_show_diff_if_expected_value_is_single_line() -> Result<()> {
         let result = verify_that!(
@@ -152,7 +152,7 @@ mod tests {
     use super::subset_of;
     use crate::prelude::*;
     use indoc::indoc;
-    use std::collections::HashSet;
+    use std::collections::{BTreeSet, HashSet};
 
     #[test]
     fn subset_of_matches_empty_vec() -> Result<()> {
@@ -270,4 +270,10 @@ mod tests {
             ))))
         )
     }
+
+    #[test]
+    fn subset_of_matches_with_expected_btree_set() -> Result<()> {
+        let value = vec!["a", "c", "a"];
+        verify_that!(value, subset_of(BTreeSet::from(["a", "b", "c"])))
+    }
 }
@@ -117,12 +117,14 @@ where
     }
 
     fn explain_match(&self, actual: &ActualT) -> Description {
-        let missing_items: Vec<_> = self
-            .subset
-            .into_iter()
-            .filter(|expected_item| actual_is_missing(actual, expected_item))
-            .map(|expected_item| format!("{expected_item:#?}"))
-            .collect();
+        let mut missing_items: Vec<&ElementT> = vec![];
+        for expected_item in &self.subset {
+            if actual_is_missing(actual, expected_item) && !missing_items.contains(&expected_item) {
+                missing_items.push(expected_item);
+            }
+        }
+        let missing_items: Vec<_> =
+            missing_items.into_iter().map(|item| format!("{item:#?}")).collect();
         match missing_items.len() {
             0 => "whose no element is missing".into(),
             1 => format!("whose element {} is missing", &missing_items[0]).into(),
@@ -153,7 +155,7 @@ mod tests {
     use super::superset_of;
     use crate::prelude::*;
     use indoc::indoc;
-    use std::collections::HashSet;
+    use std::collections::{BTreeSet, HashSet};
 
     #[test]
     fn superset_of_matches_empty_vec() -> Result<()> {
@@ -271,4 +273,25 @@ mod tests {
             ))))
         )
     }
+
+    #[test]
+    fn superset_of_lists_repeated_missing_item_once() -> Result<()> {
+        let result = verify_that!(vec![3], superset_of([1, 3, 1]));
+
+        verify_that!(
+            result,
+            err(displays_as(contains_substring(indoc!(
+                "
+                    Actual: [3],
+                      whose element 1 is missing
+                "
+            ))))
+        )
+    }
+
+    #[test]
+    fn superset_of_matches_with_expected_btree_set() -> Result<()> {
+        let value = vec!["a", "b", "c"];
+        verify_that!(value, superset_of(BTreeSet::from(["c", "a"])))
+    }
 }
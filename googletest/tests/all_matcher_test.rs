@@ -69,7 +69,6 @@ fn mismatch_description_two_failed_matchers() -> Result<()> {
 fn mismatch_description_empty_matcher() -> Result<()> {
     verify_that!(all!().explain_match("Three"), displays_as(eq("which is anything")))
 }
-#[cfg(do_not_compile)]
 
 #[test]
 fn all_multiple_failed_assertions() -> Result<()> {
@@ -90,7 +89,6 @@ fn all_multiple_failed_assertions() -> Result<()> {
         ))))
     )
 }
-#[cfg(do_not_compile)]
 
 #[test]
 fn formats_error_message_correctly_when_all_is_inside_some() -> Result<()> {
@@ -114,8 +112,6 @@ fn formats_error_message_correctly_when_all_is_inside_some() -> Result<()> {
     )
 }
 
-#[cfg(do_not_compile)]
-
 #[test]
 fn formats_error_message_correctly_when_all_is_inside_ok() -> Result<()> {
     let value: std::result::Result<i32, std::io::Error> = Ok(4);
@@ -138,8 +134,6 @@ fn formats_error_message_correctly_when_all_is_inside_ok() -> Result<()> {
     )
 }
 
-#[cfg(do_not_compile)]
-
 #[test]
 fn formats_error_message_correctly_when_all_is_inside_err() -> Result<()> {
     let value: std::result::Result<(), &'static str> = Err("An error");
@@ -15,8 +15,7 @@
 use googletest::matcher::Matcher;
 use googletest::prelude::*;
 use indoc::indoc;
-#[cfg(do_not_compile)]
-mod tests {
+
 #[test]
 fn does_not_match_value_when_list_is_empty() -> Result<()> {
     verify_that!((), not(any!()))
@@ -42,6 +41,14 @@ fn matches_value_with_second_of_two_matching_components() -> Result<()> {
     verify_that!("A string", any!(starts_with("string"), starts_with("A")))
 }
 
+#[test]
+fn matches_value_with_any_of_several_shapes() -> Result<()> {
+    verify_that!(
+        vec!["http://example.com", "ftp://example.com"],
+        each(any![starts_with("http"), starts_with("ftp")])
+    )
+}
+
 #[test]
 fn supports_trailing_comma() -> Result<()> {
     verify_that!(
@@ -90,4 +97,3 @@ fn all_multiple_failed_assertions() -> Result<()> {
         ))))
     )
 }
-}
\ No newline at end of file
@@ -1,5 +1,4 @@
 use googletest::prelude::*;
-use std::marker::PhantomData;
 
 #[derive(Debug)]
 struct ArenaHolder<'a, T: ?Sized> {
@@ -36,7 +35,7 @@ impl<'a> ArenaHolder<'a, Strukt> {
 
 #[test]
 fn check() -> Result<()> {
-    let arena = vec![Strukt { a_field: 33, a_string: "something".to_string() }];
+    let arena = [Strukt { a_field: 33, a_string: "something".to_string() }];
     let holder = ArenaHolder { value: &arena[0] };
 
     verify_that!(holder.get_a_field(), eq(33))?;
@@ -15,8 +15,7 @@
 use googletest::matcher::Matcher;
 use googletest::prelude::*;
 use indoc::indoc;
-#[cfg(do_not_compile)]
-mod test {
+
 #[test]
 fn elements_are_matches_vector() -> Result<()> {
     let value = vec![1, 2, 3];
@@ -123,4 +122,23 @@ fn elements_are_works_when_matcher_is_created_in_subroutine() -> Result<()> {
 fn elements_are_implicitly_called() -> Result<()> {
     verify_that!(vec![1, 2, 3], [eq(1), eq(2), eq(3)])
 }
-}
\ No newline at end of file
+
+#[test]
+fn elements_are_implicitly_called_produces_per_index_failure_message() -> Result<()> {
+    let result = verify_that!(vec![1, 4, 3], [eq(1), gt(5), lt(2)]);
+    verify_that!(
+        result,
+        err(displays_as(contains_substring(indoc!(
+            "
+                Value of: vec![1, 4, 3]
+                Expected: has elements:
+                  0. is equal to 1
+                  1. is greater than 5
+                  2. is less than 2
+                Actual: [1, 4, 3],
+                  where:
+                    * element #1 is 4, which is less than or equal to 5
+                    * element #2 is 3, which is greater than or equal to 2"
+        ))))
+    )
+}
@@ -58,7 +58,7 @@ fn struct_in_other_module_matches() -> Result<()> {
 }
 
 #[derive(Debug)]
-struct Tuple(i32, String);
+struct Tuple(i32, #[allow(dead_code)] String);
 
 #[test]
 fn tuple_matches_with_index() -> Result<()> {
@@ -75,7 +75,6 @@ fn matches_enum_value() -> Result<()> {
 
     verify_that!(value, field!(AnEnum::AValue.0, eq(123)))
 }
-#[cfg(do_not_compile)]
 
 #[test]
 fn shows_correct_failure_message_for_wrong_struct_entry() -> Result<()> {
@@ -107,7 +106,6 @@ fn does_not_match_enum_value_with_wrong_enum_variant() -> Result<()> {
 
     verify_that!(value, not(field!(AnEnum::AValue.0, eq(123))))
 }
-#[cfg(do_not_compile)]
 
 #[test]
 fn shows_correct_failure_message_for_wrong_enum_value() -> Result<()> {
@@ -128,7 +126,6 @@ fn shows_correct_failure_message_for_wrong_enum_value() -> Result<()> {
         err(displays_as(contains_substring("which has the wrong enum variant `AnotherValue`")))
     )
 }
-#[cfg(do_not_compile)]
 
 #[test]
 fn shows_correct_failure_message_for_wrong_enum_value_with_tuple_field() -> Result<()> {
@@ -136,6 +133,7 @@ fn shows_correct_failure_message_for_wrong_enum_value_with_tuple_field() -> Resu
     enum AnEnum {
         #[allow(dead_code)] // This variant is intentionally unused.
         AValue(u32),
+        #[allow(dead_code)] // The field is only read through Debug.
         AnotherValue(u32),
     }
     let value = AnEnum::AnotherValue(123);
@@ -147,7 +145,6 @@ fn shows_correct_failure_message_for_wrong_enum_value_with_tuple_field() -> Resu
         err(displays_as(contains_substring("which has the wrong enum variant `AnotherValue`")))
     )
 }
-#[cfg(do_not_compile)]
 
 #[test]
 fn shows_correct_failure_message_for_wrong_enum_value_with_named_field() -> Result<()> {
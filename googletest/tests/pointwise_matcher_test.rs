@@ -14,8 +14,7 @@
 
 use googletest::prelude::*;
 use indoc::indoc;
-#[cfg(do_not_compile)]
-mod tests {
+
 #[test]
 fn pointwise_matches_single_element() -> Result<()> {
     let value = vec![1];
@@ -169,4 +168,3 @@ fn pointwise_matches_single_element_with_three_containers() -> Result<()> {
         pointwise!(|v, t, u| near(v, t * u), vec![1.0f32], vec![0.0001f32], vec![0.5f32])
     )
 }
-}
\ No newline at end of file
@@ -133,15 +133,15 @@ fn describes_itself_in_not_matching_case() -> Result<()> {
         displays_as(eq("has property `get_property()`, which isn't equal to 1"))
     )
 }
-#[cfg(do_not_compile)]
+
+impl SomeStruct {
+    fn get_a_collection(&self) -> Vec<u32> {
+        vec![]
+    }
+}
 
 #[test]
 fn explains_mismatch_referencing_explanation_of_inner_matcher() -> Result<()> {
-    impl SomeStruct {
-        fn get_a_collection(&self) -> Vec<u32> {
-            vec![]
-        }
-    }
     let value = SomeStruct { a_property: 2 };
     let result = verify_that!(value, property!(SomeStruct.get_a_collection(), container_eq([1])));
 
@@ -168,16 +168,16 @@ fn describes_itself_in_not_matching_case_for_ref() -> Result<()> {
         displays_as(eq("has property `get_property_ref()`, which isn't equal to 1"))
     )
 }
-#[cfg(do_not_compile)]
+
+impl SomeStruct {
+    fn get_a_collection_ref(&self) -> &[u32] {
+        static EMPTY_COLLECTION: Vec<u32> = vec![];
+        &EMPTY_COLLECTION
+    }
+}
 
 #[test]
 fn explains_mismatch_referencing_explanation_of_inner_matcher_for_ref() -> Result<()> {
-    static EMPTY_COLLECTION: Vec<u32> = vec![];
-    impl SomeStruct {
-        fn get_a_collection_ref(&self) -> &[u32] {
-            &EMPTY_COLLECTION
-        }
-    }
     let value = SomeStruct { a_property: 2 };
     let result =
         verify_that!(value, property!(*SomeStruct.get_a_collection_ref(), container_eq([1])));
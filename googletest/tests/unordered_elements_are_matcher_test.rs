@@ -297,7 +297,6 @@ fn contains_each_explains_missing_elements_in_mismatch() -> Result<()> {
         displays_as(eq("which has no elements matching the expected elements #2, #3"))
     )
 }
-#[cfg(do_not_compile)]
 
 #[test]
 fn contains_each_explains_mismatch_due_to_no_graph_matching_found() -> Result<()> {
@@ -390,7 +389,6 @@ fn is_contained_in_explains_missing_elements_in_mismatch() -> Result<()> {
         displays_as(eq("whose elements #0, #1 do not match any expected elements"))
     )
 }
-#[cfg(do_not_compile)]
 
 #[test]
 fn is_contained_in_explains_mismatch_due_to_no_graph_matching_found() -> Result<()> {
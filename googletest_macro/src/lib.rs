@@ -12,8 +12,17 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use quote::quote;
-use syn::{parse_macro_input, Attribute, ItemFn, ReturnType};
+use proc_macro2::Ident;
+use quote::{format_ident, quote, quote_spanned, ToTokens};
+use syn::{
+    bracketed,
+    parse::{Parse, ParseStream},
+    parse_macro_input, parse_quote,
+    punctuated::Punctuated,
+    spanned::Spanned,
+    Attribute, Expr, ExprArray, FnArg, GenericParam, Generics, ItemFn, Pat, ReturnType, Token,
+    Type, Visibility,
+};
 
 /// Marks a test to be run by the Google Rust test runner.
 ///
@@ -40,13 +49,82 @@ use syn::{parse_macro_input, Attribute, ItemFn, ReturnType};
 /// }
 /// ```
 ///
+/// A test may also take fixtures as parameters of the form `name: &mut F` or
+/// `name: &F`, where `F` implements [`googletest::fixtures::Fixture`]. Each
+/// fixture is set up before the test body runs and torn down afterwards. A
+/// failure during tear-down is recorded as a failure of the same test:
+///
+/// ```ignore
+/// #[googletest::test]
+/// fn should_work(db: &mut DbFixture) {
+///     expect_that!(db.query(), ok(anything()));
+/// }
+/// ```
+///
+/// A test can be run once for each of a list of values by passing them as
+/// `values = [...]`. The first parameter of the test function takes the
+/// value; any further parameters are fixtures. This generates a module named
+/// after the test function containing one test per value, each named after
+/// its position and the source of the value expression:
+///
+/// ```ignore
+/// #[googletest::test(values = [1, 2, 3])]
+/// fn should_be_positive(value: i32) {
+///     expect_that!(value, gt(0));
+/// }
+/// ```
+///
+/// This generates the tests `should_be_positive::case_1_1`,
+/// `should_be_positive::case_2_2`, and `should_be_positive::case_3_3`. Each
+/// failure of one of them carries a trace, as added by `scoped_trace!`, with
+/// the `Debug` representation of its value.
+///
+/// Similarly, a generic test can be run once for each of a list of types by
+/// passing them as `types = [...]`. The test function must have exactly one
+/// generic parameter, which is a type. Each generated test calls the test
+/// function with one of the listed types:
+///
+/// ```ignore
+/// #[googletest::test(types = [InMemoryStorage, FileStorage])]
+/// fn should_store_value<S: Storage + Default>() {
+///     let mut storage = S::default();
+///     storage.put("key", "value");
+///     expect_that!(storage.get("key"), some(eq("value")));
+/// }
+/// ```
+///
+/// This generates the tests `should_store_value::case_1_in_memory_storage`
+/// and `should_store_value::case_2_file_storage`. Each failure of one of them
+/// carries a trace with the name of its type.
+///
 /// [`googletest::Result`]: type.Result.html
+/// [`googletest::fixtures::Fixture`]: fixtures/trait.Fixture.html
 #[proc_macro_attribute]
 pub fn test(
-    _args: proc_macro::TokenStream,
+    args: proc_macro::TokenStream,
     input: proc_macro::TokenStream,
 ) -> proc_macro::TokenStream {
-    let mut parsed_fn = parse_macro_input!(input as ItemFn);
+    let parsed_fn = parse_macro_input!(input as ItemFn);
+    let output = if args.is_empty() {
+        generate_test(parsed_fn, None)
+    } else {
+        match parse_macro_input!(args as TestArguments) {
+            TestArguments::Values(values) => generate_parameterized_tests(parsed_fn, values),
+            TestArguments::Types(types) => generate_typed_tests(parsed_fn, types),
+        }
+    };
+    output.unwrap_or_else(syn::Error::into_compile_error).into()
+}
+
+/// Generates the test function for `parsed_fn`.
+///
+/// If `parameter` is present, then the test body runs with the parameter bound
+/// to its value, and each failure of the test carries a trace describing the
+/// parameter.
+fn generate_test(
+    mut parsed_fn: ItemFn,
+    parameter: Option<TestParameter>,
+) -> Result<proc_macro2::TokenStream, syn::Error> {
     let attrs = parsed_fn.attrs.drain(..).collect::<Vec<_>>();
     let (mut sig, block) = (parsed_fn.sig, parsed_fn.block);
     let output_type = match sig.output.clone() {
@@ -54,6 +132,54 @@ pub fn test(
         ReturnType::Default => None,
     };
     sig.output = ReturnType::Default;
+    // With rstest, the parameters of the test function are its test cases rather than fixtures.
+    let fixtures =
+        if attrs.iter().any(is_rstest_attribute) { vec![] } else { extract_fixtures(&mut sig)? };
+    let fixture_patterns = fixtures.iter().map(|fixture| &fixture.pattern).collect::<Vec<_>>();
+    let fixture_types = fixtures.iter().map(|fixture| &fixture.reference_type).collect::<Vec<_>>();
+    let fixture_arguments = fixtures.iter().map(Fixture::argument).collect::<Vec<_>>();
+    let (parameter_setup, block, parameter_trace) = match parameter {
+        Some(TestParameter::Value { pattern, parameter_type, value }) => {
+            let name = quote!(#pattern).to_string();
+            let source_location = source_location_of(&value);
+            (
+                quote! {
+                    let __googletest_parameter: #parameter_type = #value;
+                    let __googletest_parameter_description =
+                        format!("parameter {} = {:?}", #name, __googletest_parameter);
+                },
+                quote! {{
+                    let #pattern: #parameter_type = __googletest_parameter;
+                    #block
+                }},
+                quote! {
+                    TestOutcome::add_trace_to_current_test(
+                        __googletest_parameter_description,
+                        #source_location,
+                    );
+                },
+            )
+        }
+        Some(TestParameter::Type { name, concrete_type }) => {
+            let name = name.to_string();
+            let source_location = source_location_of(&concrete_type);
+            (
+                quote! {},
+                quote! { #block },
+                quote! {
+                    TestOutcome::add_trace_to_current_test(
+                        format!(
+                            "type parameter {} = {}",
+                            #name,
+                            std::any::type_name::<#concrete_type>(),
+                        ),
+                        #source_location,
+                    );
+                },
+            )
+        }
+        None => (quote! {}, quote! { #block }, quote! {}),
+    };
     let (maybe_closure, invocation) = if sig.asyncness.is_some() {
         (
             // In the async case, the ? operator returns from the *block* rather than the
@@ -62,7 +188,10 @@ pub fn test(
             // so we can't use the same solution as the sync case below.
             quote! {},
             quote! {
-                async { #block }.await
+                async {
+                    #(let #fixture_patterns: #fixture_types = #fixture_arguments;)*
+                    #block
+                }.await
             },
         )
     } else {
@@ -71,34 +200,56 @@ pub fn test(
             // create a separate closure from which the ? operator can return in order to capture
             // the output.
             quote! {
-                let test = move || #block;
+                let test = move |#(#fixture_patterns: #fixture_types),*| #block;
             },
             quote! {
-                test()
+                test(#(#fixture_arguments),*)
             },
         )
     };
-    let function = if let Some(output_type) = output_type {
-        quote! {
-            #(#attrs)*
-            #sig -> std::result::Result<(), googletest::internal::test_outcome::TestFailure> {
-                #maybe_closure
-                use googletest::internal::test_outcome::TestOutcome;
-                TestOutcome::init_current_test_outcome();
+    let body = if let Some(output_type) = output_type {
+        let invocation = with_fixtures(
+            &fixtures,
+            quote! {{
                 let result: #output_type = #invocation;
-                TestOutcome::close_current_test_outcome(result)
-            }
+                result
+            }},
+            quote! { ::core::result::Result::Ok(()) },
+        );
+        quote! {
+            #parameter_setup
+            #maybe_closure
+            use googletest::internal::test_outcome::TestOutcome;
+            TestOutcome::init_current_test_outcome();
+            #parameter_trace
+            let result: #output_type = #invocation;
+            TestOutcome::close_current_test_outcome(result)
         }
     } else {
+        let invocation = with_fixtures(&fixtures, invocation, quote! {});
         quote! {
-            #(#attrs)*
-            #sig -> std::result::Result<(), googletest::internal::test_outcome::TestFailure> {
-                #maybe_closure
-                use googletest::internal::test_outcome::TestOutcome;
-                TestOutcome::init_current_test_outcome();
-                #invocation;
-                TestOutcome::close_current_test_outcome(googletest::Result::Ok(()))
-            }
+            #parameter_setup
+            #maybe_closure
+            use googletest::internal::test_outcome::TestOutcome;
+            TestOutcome::init_current_test_outcome();
+            #parameter_trace
+            #invocation;
+            TestOutcome::close_current_test_outcome(googletest::Result::Ok(()))
+        }
+    };
+    let body = if sig.asyncness.is_some() {
+        // The test context is kept with the future rather than the thread, since a multi-threaded
+        // executor may poll the future on a different thread each time.
+        quote! {
+            googletest::context::WithTestContext::isolated(async { #body }).await
+        }
+    } else {
+        body
+    };
+    let function = quote! {
+        #(#attrs)*
+        #sig -> std::result::Result<(), googletest::internal::test_outcome::TestFailure> {
+            #body
         }
     };
     let output = if attrs.iter().any(is_test_attribute) {
@@ -109,10 +260,217 @@ pub fn test(
             #function
         }
     };
-    output.into()
+    Ok(output)
+}
+
+/// The arguments of a value-parameterized or typed test.
+enum TestArguments {
+    /// `values = [...]`, listing the values of the test parameter.
+    Values(ExprArray),
+    /// `types = [...]`, listing the types for the type parameter.
+    Types(Punctuated<Type, Token![,]>),
+}
+
+impl Parse for TestArguments {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name: Ident = input.parse()?;
+        input.parse::<Token![=]>()?;
+        let arguments = if name == "values" {
+            Self::Values(input.parse()?)
+        } else if name == "types" {
+            let types;
+            bracketed!(types in input);
+            Self::Types(types.parse_terminated(Type::parse, Token![,])?)
+        } else {
+            return Err(syn::Error::new(
+                name.span(),
+                "expected `values = [...]` listing the values of the test parameter or \
+                `types = [...]` listing the types for the type parameter",
+            ));
+        };
+        input.parse::<Option<Token![,]>>()?;
+        Ok(arguments)
+    }
+}
+
+/// The parameter of a value-parameterized or typed test bound to one of its
+/// values or types.
+enum TestParameter {
+    Value { pattern: Box<Pat>, parameter_type: Box<Type>, value: Expr },
+    Type { name: Ident, concrete_type: Type },
+}
+
+/// Generates a module named after `parsed_fn` containing one test for each of
+/// `values`.
+///
+/// The first parameter of `parsed_fn` takes the value. Any further parameters
+/// are fixtures.
+fn generate_parameterized_tests(
+    parsed_fn: ItemFn,
+    values: ExprArray,
+) -> Result<proc_macro2::TokenStream, syn::Error> {
+    let mut inputs = parsed_fn.sig.inputs.clone().into_iter();
+    let Some(FnArg::Typed(parameter)) = inputs.next() else {
+        return Err(syn::Error::new(
+            parsed_fn.sig.span(),
+            "a test with `values` must take the parameter as its first parameter",
+        ));
+    };
+    let cases = values
+        .elems
+        .iter()
+        .enumerate()
+        .map(|(index, value)| {
+            let mut case_fn = parsed_fn.clone();
+            case_fn.vis = Visibility::Inherited;
+            case_fn.sig.ident = case_name(index, value);
+            case_fn.sig.inputs = inputs.clone().collect();
+            generate_test(
+                case_fn,
+                Some(TestParameter::Value {
+                    pattern: parameter.pat.clone(),
+                    parameter_type: parameter.ty.clone(),
+                    value: value.clone(),
+                }),
+            )
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(generate_test_module(&parsed_fn, cases))
+}
+
+/// Generates a module named after `parsed_fn` containing one test for each of
+/// `types`.
+///
+/// `parsed_fn` must have exactly one generic parameter, which is a type. It is
+/// placed unchanged in the module and each test calls it with one of `types`.
+fn generate_typed_tests(
+    parsed_fn: ItemFn,
+    types: Punctuated<Type, Token![,]>,
+) -> Result<proc_macro2::TokenStream, syn::Error> {
+    let generics = &parsed_fn.sig.generics;
+    let type_parameter = match generics.params.first() {
+        Some(GenericParam::Type(type_parameter)) if generics.params.len() == 1 => type_parameter,
+        _ => {
+            return Err(syn::Error::new(
+                generics.span(),
+                "a test with `types` must have exactly one generic parameter, which is a type",
+            ))
+        }
+    };
+    // The test attributes go on the generated tests rather than the generic function.
+    let attrs =
+        parsed_fn.attrs.iter().filter(|attr| !is_test_attribute(attr)).cloned().collect();
+    let generic_fn = ItemFn { attrs, vis: Visibility::Inherited, ..parsed_fn.clone() };
+    let generic_fn_name = &parsed_fn.sig.ident;
+    let (inputs, arguments): (Punctuated<FnArg, Token![,]>, Vec<Ident>) = parsed_fn
+        .sig
+        .inputs
+        .iter()
+        .enumerate()
+        .map(|(index, input)| {
+            let argument = format_ident!("__googletest_argument_{index}");
+            let mut input = input.clone();
+            if let FnArg::Typed(pattern_type) = &mut input {
+                pattern_type.pat = parse_quote!(#argument);
+            }
+            (input, argument)
+        })
+        .unzip();
+    let maybe_await = parsed_fn.sig.asyncness.map(|_| quote! { .await });
+    let mut cases = types
+        .iter()
+        .enumerate()
+        .map(|(index, concrete_type)| {
+            let mut case_fn = parsed_fn.clone();
+            case_fn.vis = Visibility::Inherited;
+            case_fn.sig.ident = case_name(index, concrete_type);
+            case_fn.sig.generics = Generics::default();
+            case_fn.sig.inputs = inputs.clone();
+            case_fn.block = parse_quote! {{
+                #generic_fn_name::<#concrete_type>(#(#arguments),*)#maybe_await
+            }};
+            generate_test(
+                case_fn,
+                Some(TestParameter::Type {
+                    name: type_parameter.ident.clone(),
+                    concrete_type: concrete_type.clone(),
+                }),
+            )
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    cases.push(generic_fn.into_token_stream());
+    Ok(generate_test_module(&parsed_fn, cases))
+}
+
+/// Generates a module with the name and visibility of `parsed_fn` containing
+/// the tests `cases`.
+fn generate_test_module(
+    parsed_fn: &ItemFn,
+    cases: Vec<proc_macro2::TokenStream>,
+) -> proc_macro2::TokenStream {
+    let ItemFn { vis, sig, .. } = parsed_fn;
+    let module = &sig.ident;
+    quote! {
+        #vis mod #module {
+            #[allow(unused_imports)]
+            use super::*;
+
+            #(#cases)*
+        }
+    }
+}
+
+/// Returns an expression evaluating to the `SourceLocation` of `tokens` in the
+/// arguments of the attribute.
+fn source_location_of(tokens: &impl ToTokens) -> proc_macro2::TokenStream {
+    quote_spanned! {tokens.span()=>
+        googletest::internal::source_location::SourceLocation::new(file!(), line!(), column!())
+    }
+}
+
+/// Returns the name of the test for the parameter value or type `parameter` at
+/// position `index`.
+///
+/// The name is `case_<index>_<parameter>`, where `<parameter>` is derived from
+/// the source of `parameter` in snake case, so that the test output shows
+/// which value or type was used.
+fn case_name(index: usize, parameter: &impl ToTokens) -> Ident {
+    const MAX_PARAMETER_NAME_LENGTH: usize = 32;
+    let mut parameter_name = String::new();
+    let mut previous = ' ';
+    for c in parameter.to_token_stream().to_string().chars() {
+        if c.is_ascii_alphanumeric() {
+            if c.is_ascii_uppercase()
+                && (previous.is_ascii_lowercase() || previous.is_ascii_digit())
+            {
+                parameter_name.push('_');
+            }
+            parameter_name.push(c.to_ascii_lowercase());
+        } else if !parameter_name.is_empty() && !parameter_name.ends_with('_') {
+            parameter_name.push('_');
+        }
+        if parameter_name.len() >= MAX_PARAMETER_NAME_LENGTH {
+            break;
+        }
+        previous = c;
+    }
+    let parameter_name = parameter_name.trim_end_matches('_');
+    if parameter_name.is_empty() {
+        format_ident!("case_{}", index + 1)
+    } else {
+        format_ident!("case_{}_{}", index + 1, parameter_name)
+    }
 }
 
 fn is_test_attribute(attr: &Attribute) -> bool {
+    let last_segment = match attr.path().segments.last() {
+        Some(last_segment) => last_segment,
+        None => return false,
+    };
+    last_segment.ident == "test" || is_rstest_attribute(attr)
+}
+
+fn is_rstest_attribute(attr: &Attribute) -> bool {
     let first_segment = match attr.path().segments.first() {
         Some(first_segment) => first_segment,
         None => return false,
@@ -121,8 +479,97 @@ fn is_test_attribute(attr: &Attribute) -> bool {
         Some(last_segment) => last_segment,
         None => return false,
     };
-    last_segment.ident == "test"
-        || (first_segment.ident == "rstest"
-            && last_segment.ident == "rstest"
-            && attr.path().segments.len() <= 2)
+    first_segment.ident == "rstest"
+        && last_segment.ident == "rstest"
+        && attr.path().segments.len() <= 2
+}
+
+/// A fixture taken as a parameter `pattern: &mut fixture_type` or
+/// `pattern: &fixture_type` by a test function.
+struct Fixture {
+    pattern: Box<Pat>,
+    reference_type: Box<Type>,
+    fixture_type: Box<Type>,
+    mutable: bool,
+    variable: Ident,
+}
+
+impl Fixture {
+    /// The expression passing the fixture to the test body.
+    fn argument(&self) -> proc_macro2::TokenStream {
+        let variable = &self.variable;
+        if self.mutable {
+            quote! { &mut #variable }
+        } else {
+            quote! { &#variable }
+        }
+    }
+}
+
+/// Removes the parameters from `sig` and returns them as fixtures.
+///
+/// Returns an error if any parameter is not a reference to a fixture.
+fn extract_fixtures(sig: &mut syn::Signature) -> Result<Vec<Fixture>, syn::Error> {
+    std::mem::take(&mut sig.inputs)
+        .into_iter()
+        .enumerate()
+        .map(|(index, input)| {
+            let FnArg::Typed(pattern_type) = input else {
+                return Err(syn::Error::new(input.span(), "a test function cannot take `self`"));
+            };
+            let Type::Reference(reference) = pattern_type.ty.as_ref() else {
+                return Err(syn::Error::new(
+                    pattern_type.ty.span(),
+                    "the parameters of a test function must be fixtures of the form \
+                    `name: &mut Fixture` or `name: &Fixture`",
+                ));
+            };
+            Ok(Fixture {
+                fixture_type: reference.elem.clone(),
+                mutable: reference.mutability.is_some(),
+                pattern: pattern_type.pat,
+                reference_type: pattern_type.ty.clone(),
+                variable: format_ident!("__googletest_fixture_{index}"),
+            })
+        })
+        .collect()
+}
+
+/// Wraps `body` so that it runs between the set-up and tear-down of each of
+/// `fixtures`.
+///
+/// Failures of set-up and tear-down are logged as failures of the current
+/// test. Each fixture is torn down even if `body` panics. If the set-up of a
+/// fixture fails, then `body` does not run, the fixtures already set up are
+/// torn down, and the whole expression evaluates to `skipped`.
+fn with_fixtures(
+    fixtures: &[Fixture],
+    body: proc_macro2::TokenStream,
+    skipped: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    fixtures.iter().rev().fold(body, |body, fixture| {
+        let Fixture { fixture_type, variable, mutable, .. } = fixture;
+        let binding = if *mutable {
+            quote! { mut #variable }
+        } else {
+            quote! { #variable }
+        };
+        quote! {
+            match <#fixture_type as googletest::fixtures::Fixture>::set_up() {
+                ::core::result::Result::Ok(fixture) => {
+                    // The guard tears the fixture down when it goes out of scope, including
+                    // when the body panics.
+                    let #binding = googletest::internal::fixture_guard::FixtureGuard::new(fixture);
+                    let result = #body;
+                    result
+                }
+                ::core::result::Result::Err(failure) => {
+                    googletest::GoogleTestSupport::and_log_failure(
+                        ::core::result::Result::<(), _>::Err(failure),
+                    );
+                    #skipped
+                }
+            }
+        }
+    })
 }
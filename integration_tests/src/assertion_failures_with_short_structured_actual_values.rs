@@ -13,8 +13,6 @@
 // limitations under the License.
 
 fn main() {}
-#[cfg(do_not_compile)]
-
 #[cfg(test)]
 mod tests {
     use googletest::prelude::*;
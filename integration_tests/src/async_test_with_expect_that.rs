@@ -17,6 +17,7 @@ fn main() {}
 #[deny(warnings)]
 #[cfg(test)]
 mod tests {
+    use googletest::context::TestContext;
     use googletest::prelude::*;
     use std::time::Duration;
     use tokio::time::sleep;
@@ -36,4 +37,28 @@ mod tests {
         verify_that!(3, eq(4))?;
         Ok(())
     }
+
+    #[googletest::test]
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn async_test_failure_on_multi_thread_runtime() {
+        sleep(Duration::from_millis(1)).await;
+        expect_that!(4, eq(5));
+    }
+
+    #[googletest::test]
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn async_test_failure_in_task_on_multi_thread_runtime() {
+        let context = TestContext::current();
+        let tasks = (0..4)
+            .map(|value| {
+                tokio::spawn(context.wrap_future(async move {
+                    sleep(Duration::from_millis(1)).await;
+                    expect_that!(value, not(eq(2)));
+                }))
+            })
+            .collect::<Vec<_>>();
+        for task in tasks {
+            task.await.unwrap();
+        }
+    }
 }
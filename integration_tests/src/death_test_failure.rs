@@ -0,0 +1,44 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+fn main() {}
+
+#[deny(warnings)]
+#[cfg(test)]
+mod tests {
+    use googletest::prelude::*;
+
+    #[googletest::test]
+    fn does_not_exit() -> Result<()> {
+        verify_exit!(|| eprintln!("Still running"), exited_with_code(eq(2)))
+    }
+
+    #[googletest::test]
+    fn exits_with_other_code_and_stderr() {
+        expect_exit!(
+            || {
+                eprintln!("Unexpected input");
+                std::process::exit(1)
+            },
+            exited_with_code(eq(2)),
+            contains_substring("Invalid configuration")
+        );
+        expect_that!(1, eq(2));
+    }
+
+    #[googletest::test]
+    #[ignore]
+    fn ignored_test_exits() -> Result<()> {
+        verify_exit!(|| std::process::exit(2), exited_with_code(eq(2)))
+    }
+}
@@ -0,0 +1,45 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+fn main() {}
+
+#[deny(warnings)]
+#[cfg(test)]
+mod tests {
+    use googletest::context::{spawn, spawn_scoped, TestContext};
+    use googletest::prelude::*;
+
+    #[googletest::test]
+    fn failure_in_spawned_thread() {
+        spawn(|| expect_that!(2, eq(3))).join().unwrap();
+    }
+
+    #[googletest::test]
+    fn failure_in_scoped_thread() {
+        std::thread::scope(|scope| {
+            spawn_scoped(scope, || expect_that!(3, eq(4)));
+        });
+    }
+
+    #[googletest::test]
+    fn failure_in_named_thread() {
+        let context = TestContext::current();
+        std::thread::Builder::new()
+            .name("worker-1".into())
+            .spawn(move || context.run(|| expect_that!(4, eq(5))))
+            .unwrap()
+            .join()
+            .unwrap();
+    }
+}
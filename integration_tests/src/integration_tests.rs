@@ -25,7 +25,6 @@ mod tests {
         let value = 2;
         verify_that!(value, eq(2))
     }
-    #[cfg(do_not_compile)]
 
     #[test]
     fn verify_that_supports_trailing_comma() -> Result<()> {
@@ -33,16 +32,15 @@ mod tests {
         verify_that!(value, eq(2),)
     }
 
-    // #[test]
-    // fn should_pass_with_omitted_elements_are() -> Result<()> {
-    //     verify_that!(vec![1, 2], [eq(1), eq(2)])
-    // }
+    #[test]
+    fn should_pass_with_omitted_elements_are() -> Result<()> {
+        verify_that!(vec![1, 2], [eq(1), eq(2)])
+    }
 
     #[test]
     fn should_pass_with_omitted_unordered_elements_are() -> Result<()> {
         verify_that!(vec![1, 2], {eq(2), eq(1)})
     }
-    #[cfg(do_not_compile)]
 
     #[test]
     fn verify_that_with_short_elements_are_syntax_supports_trailing_comma() -> Result<()> {
@@ -118,7 +116,6 @@ mod tests {
 
         verify_that!(status.success(), eq(false))
     }
-    #[cfg(do_not_compile)]
 
     #[test]
     fn should_output_failure_message_on_assertion_failure() -> Result<()> {
@@ -134,7 +131,6 @@ mod tests {
                   at .*integration_tests/src/simple_assertion_failure.rs:[0-9]+:9"})
         )
     }
-    #[cfg(do_not_compile)]
 
     #[test]
     fn should_output_failure_message_on_assertion_failure_with_assert_that() -> Result<()> {
@@ -152,7 +148,6 @@ mod tests {
                 "})
         )
     }
-    #[cfg(do_not_compile)]
 
     #[test]
     fn should_output_failure_message_on_assertion_failure_with_expect_that() -> Result<()> {
@@ -169,7 +164,6 @@ mod tests {
                 "})
         )
     }
-    #[cfg(do_not_compile)]
 
     #[test]
     fn should_output_both_failure_messages_when_two_expect_that_assertions_fail() -> Result<()> {
@@ -483,6 +477,211 @@ mod tests {
         verify_pred!((AStruct {}).eq_predicate_as_method(1, 1))
     }
 
+    #[test]
+    fn should_verify_panic_with_matching_message() -> Result<()> {
+        let values: Vec<u32> = vec![];
+
+        verify_panics!(values[1], contains_substring("index out of bounds"))
+    }
+
+    #[googletest::test]
+    fn should_verify_panic_with_matching_message_using_expect_panic() {
+        fn panic_with_formatted_message(argument: &str) {
+            panic!("Some {argument} message");
+        }
+
+        expect_panic!(panic_with_formatted_message("formatted"), eq("Some formatted message"));
+    }
+
+    #[test]
+    fn verify_panics_should_output_failure_message_when_no_panic() -> Result<()> {
+        let output = run_external_process_in_tests_directory("panic_assertion_failure")?;
+
+        verify_that!(
+            output,
+            contains_substring(indoc! {"
+                Value of: checked_divide(1, 1)
+                Expected: panics with a message which contains a substring \"divide by zero\"
+                Actual: no panic,
+                  which did not panic
+                  at integration_tests/src/panic_assertion_failure.rs:27:9
+                "})
+        )
+    }
+
+    #[test]
+    fn expect_panic_should_output_failure_message_for_other_message() -> Result<()> {
+        let output = run_external_process_in_tests_directory("panic_assertion_failure")?;
+
+        verify_that!(
+            output,
+            contains_substring(indoc! {"
+                Value of: checked_divide(1, 0)
+                Expected: panics with a message which contains a substring \"overflow\"
+                Actual: panic with message \"attempt to divide by zero\",
+                  which panicked with a message
+                    which does not contain a substring \"overflow\"
+                  at integration_tests/src/panic_assertion_failure.rs:32:9
+                "})
+        )
+    }
+
+    #[test]
+    fn expect_panic_should_continue_after_failure() -> Result<()> {
+        let output = run_external_process_in_tests_directory("panic_assertion_failure")?;
+
+        verify_that!(output, contains_substring("Expected: is equal to 2"))
+    }
+
+    #[cfg(target_os = "linux")]
+    #[googletest::test]
+    fn should_verify_exit_code_and_stderr_of_death_test() {
+        expect_exit!(
+            || {
+                eprintln!("Fatal error");
+                std::process::exit(3)
+            },
+            exited_with_code(eq(3)),
+            contains_substring("Fatal error")
+        );
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn should_verify_death_test_killed_by_signal() -> Result<()> {
+        verify_exit!(|| std::process::abort(), killed_by_signal(eq(6)))
+    }
+
+    #[cfg(target_os = "linux")]
+    #[googletest::test]
+    fn should_run_each_closure_of_death_test_reached_several_times() {
+        for code in [2, 3] {
+            expect_exit!(move || std::process::exit(code), exited_with_code(eq(code)));
+        }
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn should_verify_death_test_which_panics() -> Result<()> {
+        verify_exit!(
+            || panic!("Out of memory"),
+            exited_with_code(eq(101)),
+            contains_substring("Out of memory")
+        )
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn verify_exit_should_output_failure_message_when_no_exit() -> Result<()> {
+        let output = run_external_process_in_tests_directory("death_test_failure")?;
+
+        verify_that!(
+            output,
+            contains_substring(indoc! {"
+                Value of: || eprintln!(\"Still running\")
+                Expected: exits with a code which is equal to 2
+                Actual: returned with stderr \"Still running\\n\",
+                  which did not exit
+                  at integration_tests/src/death_test_failure.rs:23:9
+                "})
+        )
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn expect_exit_should_output_failure_message_for_other_status_and_stderr() -> Result<()> {
+        let output = run_external_process_in_tests_directory("death_test_failure")?;
+
+        verify_that!(
+            output,
+            contains_substring(indoc! {"
+                Actual: exited with code 1 with stderr \"Unexpected input\\n\",
+                  whose exit status
+                    which exited with code 1
+                      which isn't equal to 2
+                  and whose stderr
+                    which does not contain a substring \"Invalid configuration\"
+                "})
+        )
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn verify_exit_should_run_ignored_test_in_child_process() -> Result<()> {
+        let std::process::Output { stdout, .. } = run_external_process("death_test_failure")
+            .args(["--include-ignored", "--exact", "tests::ignored_test_exits"])
+            .output()?;
+
+        verify_that!(String::from_utf8(stdout)?, contains_substring("test result: ok. 1 passed"))
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn expect_exit_should_continue_after_failure() -> Result<()> {
+        let output = run_external_process_in_tests_directory("death_test_failure")?;
+
+        verify_that!(output, contains_substring("Expected: is equal to 2"))
+    }
+
+    #[googletest::test]
+    fn capture_failures_returns_non_fatal_failures_without_failing_test() -> Result<()> {
+        let failures = capture_failures(|| {
+            let value = 2;
+            expect_that!(value, eq(3));
+            expect_that!(value, eq(2));
+            expect_that!(value, eq(4), "Some extra information");
+        });
+
+        verify_that!(
+            failures,
+            elements_are![
+                displays_as(matches_regex(indoc! {"
+                    Value of: value
+                    Expected: is equal to 3
+                    Actual: 2,
+                      which isn't equal to 3
+                      at integration_tests/src/integration_tests.rs:[0-9]+:13
+                    "})),
+                displays_as(ends_with("Some extra information\n")),
+            ]
+        )?;
+        verify_current_test_outcome()
+    }
+
+    #[test]
+    fn capture_failures_returns_fatal_failure_last() -> Result<()> {
+        let failures = capture_failures(|| {
+            expect_that!(1, eq(2));
+            verify_that!(3, eq(4))?;
+            expect_that!(5, eq(6));
+            Ok(())
+        });
+
+        verify_that!(
+            failures,
+            elements_are![
+                displays_as(starts_with("Value of: 1\n")),
+                displays_as(starts_with("Value of: 3\n")),
+            ]
+        )
+    }
+
+    #[test]
+    fn capture_failures_returns_failures_in_spawned_thread() -> Result<()> {
+        let failures = capture_failures(|| {
+            googletest::context::spawn(|| expect_that!(1, eq(2))).join().unwrap();
+        });
+
+        verify_that!(failures, elements_are![displays_as(starts_with("Value of: 1\n"))])
+    }
+
+    #[test]
+    fn capture_failures_returns_empty_list_when_no_failures() -> Result<()> {
+        let failures = capture_failures(|| expect_that!(1, eq(1)));
+
+        verify_that!(failures, empty())
+    }
+
     struct AStruct {}
 
     impl AStruct {
@@ -553,7 +752,6 @@ mod tests {
 
         verify_that!(status.success(), eq(false))
     }
-    #[cfg(do_not_compile)]
 
     #[test]
     fn fail_macro_outputs_message() -> Result<()> {
@@ -607,7 +805,6 @@ mod tests {
     fn should_display_error_correctly_without_google_test_macro() -> Result<()> {
         verify_that!(1, eq(2))
     }
-    #[cfg(do_not_compile)]
 
     #[test]
     fn failure_message_uses_pretty_print_for_actual_value_when_long_enough() -> Result<()> {
@@ -679,6 +876,50 @@ mod tests {
         verify_that!(output, contains_substring("Expected: is equal to 4"))
     }
 
+    #[googletest::test]
+    fn async_test_on_multi_thread_runtime_records_failures() -> Result<()> {
+        let output = run_external_process_in_tests_directory("async_test_with_expect_that")?;
+
+        expect_that!(
+            output,
+            contains_substring("tests::async_test_failure_on_multi_thread_runtime ... FAILED")
+        );
+        expect_that!(
+            output,
+            contains_substring(
+                "tests::async_test_failure_in_task_on_multi_thread_runtime ... FAILED"
+            )
+        );
+        expect_that!(output, contains_substring("Expected: is equal to 5"));
+        verify_that!(
+            output,
+            contains_substring(indoc! {"
+                Value of: value
+                Expected: isn't equal to 2
+                Actual: 2,
+                  which is equal to 2
+                "})
+        )
+    }
+
+    #[googletest::test]
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn async_test_passes_with_expect_that_in_tasks_on_multi_thread_runtime() {
+        let context = googletest::context::TestContext::current();
+        let tasks = (0..8)
+            .map(|value| {
+                tokio::spawn(context.wrap_future(async move {
+                    tokio::task::yield_now().await;
+                    expect_that!(value, lt(8));
+                }))
+            })
+            .collect::<Vec<_>>();
+        for task in tasks {
+            task.await.unwrap();
+        }
+        expect_that!(googletest::verify_current_test_outcome(), ok(eq(())));
+    }
+
     #[test]
     fn test_can_return_anyhow_generated_error() -> Result<()> {
         let output = run_external_process_in_tests_directory("test_returning_anyhow_error")?;
@@ -686,6 +927,489 @@ mod tests {
         verify_that!(output, contains_substring("Error from Anyhow"))
     }
 
+    struct CounterFixture {
+        count: u32,
+    }
+
+    impl Fixture for CounterFixture {
+        fn set_up() -> Result<Self> {
+            Ok(Self { count: 1 })
+        }
+
+        fn tear_down(self) -> Result<()> {
+            verify_that!(self.count, eq(2))
+        }
+    }
+
+    #[googletest::test]
+    fn test_can_mutate_fixture(counter: &mut CounterFixture) {
+        expect_that!(counter.count, eq(1));
+        counter.count += 1;
+    }
+
+    struct IncrementFixture {
+        increment: u32,
+    }
+
+    impl Fixture for IncrementFixture {
+        fn set_up() -> Result<Self> {
+            Ok(Self { increment: 1 })
+        }
+
+        fn tear_down(self) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    #[googletest::test]
+    fn test_can_take_fixture_by_shared_reference(
+        counter: &mut CounterFixture,
+        increment: &IncrementFixture,
+    ) -> Result<()> {
+        counter.count += increment.increment;
+        verify_that!(counter.count, eq(2))
+    }
+
+    #[googletest::test]
+    #[tokio::test]
+    async fn async_test_can_use_fixture(counter: &mut CounterFixture) -> Result<()> {
+        counter.count += 1;
+        verify_that!(counter.count, eq(2))
+    }
+
+    #[test]
+    fn test_with_fixtures_records_tear_down_failure_with_body_failure() -> Result<()> {
+        let output = run_external_process_in_tests_directory("test_with_fixtures")?;
+
+        verify_that!(
+            output,
+            contains_substring(indoc! {"
+                ---- tests::tear_down_failure_is_recorded_with_body_failure stdout ----
+                Value of: 2
+                Expected: is equal to 3
+                Actual: 2,
+                  which isn't equal to 3
+                  at integration_tests/src/test_with_fixtures.rs:90:9
+
+                Tear-down failed
+                "})
+        )
+    }
+
+    #[test]
+    fn test_with_fixtures_fails_passing_test_when_tear_down_fails() -> Result<()> {
+        let output = run_external_process_in_tests_directory("test_with_fixtures")?;
+
+        verify_that!(
+            output,
+            contains_substring("tests::tear_down_failure_fails_passing_test ... FAILED")
+        )
+    }
+
+    #[test]
+    fn test_with_fixtures_tears_down_after_fatal_failure() -> Result<()> {
+        let output = run_external_process_in_tests_directory("test_with_fixtures")?;
+
+        verify_that!(
+            output,
+            contains_substring(indoc! {"
+                ---- tests::tear_down_runs_after_fatal_failure stdout ----
+                Tear-down failed
+                "})
+        )
+    }
+
+    #[test]
+    fn test_with_fixtures_tears_down_after_panic() -> Result<()> {
+        let output = run_external_process_in_tests_directory("test_with_fixtures")?;
+
+        verify_that!(
+            output,
+            all!(
+                contains_substring("tests::tear_down_runs_after_panic ... FAILED"),
+                contains_substring("Tearing down while panicking: true"),
+            )
+        )
+    }
+
+    #[test]
+    fn test_with_fixtures_does_not_run_body_when_set_up_fails() -> Result<()> {
+        let output = run_external_process_in_tests_directory("test_with_fixtures")?;
+
+        verify_that!(
+            output,
+            all!(
+                contains_substring(indoc! {"
+                    ---- tests::body_does_not_run_when_set_up_fails stdout ----
+                    Setting up FirstFixture
+                    Set-up failed
+                    "}),
+                contains_substring("Tearing down FirstFixture"),
+                not(contains_substring("Body ran")),
+            )
+        )
+    }
+
+    #[test]
+    fn test_with_fixtures_tears_down_in_reverse_order() -> Result<()> {
+        let output = run_external_process_in_tests_directory("test_with_fixtures")?;
+
+        verify_that!(
+            output,
+            contains_substring(indoc! {"
+                Tearing down SecondFixture
+                Tearing down FirstFixture
+                "})
+        )
+    }
+
+    #[googletest::test(values = [1, 2, 3])]
+    fn parameterized_test_runs_with_each_value(value: u32) {
+        expect_that!(value, all!(ge(1), le(3)));
+    }
+
+    #[googletest::test(values = ["A string", "Another string"])]
+    fn parameterized_test_supports_reference_values(value: &str) -> Result<()> {
+        verify_that!(value, ends_with("string"))
+    }
+
+    #[googletest::test(values = [1, 2])]
+    fn parameterized_test_supports_fixtures(value: u32, counter: &mut CounterFixture) {
+        expect_that!(value, gt(0));
+        counter.count += 1;
+    }
+
+    #[googletest::test(values = [1, 2])]
+    #[tokio::test]
+    async fn async_parameterized_test_runs_correctly(value: u32) -> Result<()> {
+        verify_that!(value, lt(3))
+    }
+
+    #[googletest::test(values = [7])]
+    fn parameterized_test_adds_parameter_to_each_failure(value: u32) {
+        let failures = capture_failures(|| expect_that!(value, eq(0)));
+
+        expect_that!(
+            failures,
+            elements_are![displays_as(contains_substring("Trace: parameter value = 7\n"))]
+        );
+    }
+
+    #[test]
+    fn parameterized_test_has_one_test_per_value() -> Result<()> {
+        let output = run_external_process_in_tests_directory("parameterized_test_failure")?;
+
+        verify_that!(
+            output,
+            all!(
+                contains_substring("tests::value_is_even::case_1_2 ... ok"),
+                contains_substring("tests::value_is_even::case_2_3 ... FAILED"),
+                contains_substring("tests::value_is_even::case_3_4 ... ok"),
+            )
+        )
+    }
+
+    #[test]
+    fn parameterized_test_outputs_parameter_value_on_failure() -> Result<()> {
+        let output = run_external_process_in_tests_directory("parameterized_test_failure")?;
+
+        verify_that!(
+            output,
+            all!(
+                contains_substring(indoc! {"
+                    Actual: 1,
+                      which isn't equal to 0
+                      at integration_tests/src/parameterized_test_failure.rs:24:9
+                    Trace: parameter value = 3
+                      at integration_tests/src/parameterized_test_failure.rs:22:37
+                    "}),
+                contains_substring("Trace: parameter point = Point { x: -1, y: 2 }"),
+                not(contains_substring("Trace: parameter value = 2")),
+            )
+        )
+    }
+
+    #[googletest::test(types = [u8, i32, u64])]
+    fn typed_test_runs_with_each_type<T: From<u8> + PartialEq + std::fmt::Debug>() {
+        expect_that!(T::from(1), eq(T::from(1)));
+    }
+
+    #[googletest::test(types = [Vec<u32>, std::collections::BTreeSet<u32>])]
+    fn typed_test_supports_generic_types_and_fixtures<C>(counter: &mut CounterFixture)
+    where
+        C: FromIterator<u32> + IntoIterator<Item = u32>,
+    {
+        let collection: C = [1, 2].into_iter().collect();
+        expect_that!(collection.into_iter().count(), eq(2));
+        counter.count += 1;
+    }
+
+    #[googletest::test(types = [u32, u64])]
+    #[tokio::test]
+    async fn async_typed_test_runs_correctly<T: Default + PartialEq + std::fmt::Debug>(
+    ) -> Result<()> {
+        verify_that!(T::default(), eq(T::default()))
+    }
+
+    #[test]
+    fn typed_test_has_one_test_per_type() -> Result<()> {
+        let output = run_external_process_in_tests_directory("typed_test_failure")?;
+
+        verify_that!(
+            output,
+            all!(
+                contains_substring(
+                    "tests::storage_returns_stored_value::case_1_working_storage ... ok"
+                ),
+                contains_substring(
+                    "tests::storage_returns_stored_value::case_2_forgetful_storage ... FAILED"
+                ),
+            )
+        )
+    }
+
+    #[test]
+    fn typed_test_keeps_attributes_of_generic_test_function() -> Result<()> {
+        let output = run_external_process_in_tests_directory("typed_test_failure")?;
+
+        verify_that!(
+            output,
+            contains_substring(
+                "tests::attributes_apply_to_generic_test_function::case_1_working_storage ... ok"
+            )
+        )
+    }
+
+    #[test]
+    fn typed_test_outputs_type_on_failure() -> Result<()> {
+        let output = run_external_process_in_tests_directory("typed_test_failure")?;
+
+        verify_that!(
+            output,
+            contains_substring(indoc! {"
+                ---- tests::storage_returns_stored_value::case_2_forgetful_storage stdout ----
+                Value of: storage.get()
+                Expected: has a value which is equal to 1
+                Actual: None,
+                  which is None
+                  at integration_tests/src/typed_test_failure.rs:55:9
+                Trace: type parameter S = typed_test_failure::tests::ForgetfulStorage
+                "})
+        )
+    }
+
+    #[test]
+    fn scoped_trace_annotates_failure_in_its_scope() -> Result<()> {
+        let output = run_external_process_in_tests_directory("scoped_trace_failure")?;
+
+        verify_that!(
+            output,
+            all!(
+                contains_substring(indoc! {"
+                    Value of: i
+                    Expected: is less than 3
+                    Actual: 3,
+                      which is greater than or equal to 3
+                      at integration_tests/src/scoped_trace_failure.rs:26:13
+                    Trace: iteration 3
+                      at integration_tests/src/scoped_trace_failure.rs:25:13
+                    "}),
+                contains_substring("Trace: iteration 3").times(eq(1)),
+                not(contains_substring("Trace: iteration 1")),
+            )
+        )
+    }
+
+    #[test]
+    fn scoped_trace_does_not_annotate_failure_after_its_scope() -> Result<()> {
+        let output = run_external_process_in_tests_directory("scoped_trace_failure")?;
+
+        verify_that!(
+            output,
+            contains_substring(indoc! {"
+                  at integration_tests/src/scoped_trace_failure.rs:28:9
+
+                Error: See failure output above
+                "})
+        )
+    }
+
+    #[test]
+    fn scoped_trace_outputs_nested_traces_innermost_first() -> Result<()> {
+        let output = run_external_process_in_tests_directory("scoped_trace_failure")?;
+
+        verify_that!(
+            output,
+            contains_substring(indoc! {"
+                Trace: checking value 2
+                  at integration_tests/src/scoped_trace_failure.rs:38:9
+                Trace: outer scope
+                  at integration_tests/src/scoped_trace_failure.rs:33:9
+                "})
+        )
+    }
+
+    #[test]
+    fn scoped_trace_annotates_fatal_failure() -> Result<()> {
+        let output = run_external_process_in_tests_directory("scoped_trace_failure")?;
+
+        verify_that!(
+            output,
+            contains_substring(indoc! {"
+                  at integration_tests/src/scoped_trace_failure.rs:45:9
+                Trace: fatal scope
+                  at integration_tests/src/scoped_trace_failure.rs:44:9
+                "})
+        )
+    }
+
+    #[googletest::test]
+    fn should_pass_with_expect_that_in_spawned_threads() {
+        let handles = (0..4)
+            .map(|value| googletest::context::spawn(move || expect_that!(value, lt(4))))
+            .collect::<Vec<_>>();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+    }
+
+    #[test]
+    fn failure_in_spawned_thread_fails_test() -> Result<()> {
+        let output = run_external_process_in_tests_directory("failure_in_spawned_thread")?;
+
+        verify_that!(
+            output,
+            all!(
+                contains_substring("tests::failure_in_spawned_thread ... FAILED"),
+                contains_substring("tests::failure_in_scoped_thread ... FAILED"),
+                contains_substring("tests::failure_in_named_thread ... FAILED"),
+            )
+        )
+    }
+
+    #[test]
+    fn failure_in_spawned_thread_outputs_thread_name() -> Result<()> {
+        let output = run_external_process_in_tests_directory("failure_in_spawned_thread")?;
+
+        verify_that!(
+            output,
+            all!(
+                contains_substring(indoc! {"
+                    Failure in thread 'worker-1':
+                    Value of: 4
+                    Expected: is equal to 5
+                    "}),
+                contains_substring(indoc! {"
+                    Failure in thread '<unnamed>':
+                    Value of: 2
+                    Expected: is equal to 3
+                    "}),
+            )
+        )
+    }
+
+    #[::core::prelude::v1::test]
+    #[should_panic]
+    fn should_panic_when_expect_that_runs_in_thread_without_test_context() {
+        let _ = should_just_pass();
+        std::thread::spawn(|| expect_that!(123, eq(123))).join().unwrap();
+    }
+
+    #[test]
+    fn junit_xml_output_contains_each_test_and_failure() -> Result<()> {
+        let xml_output_file =
+            std::env::temp_dir().join(format!("junit_xml_output_{}.xml", std::process::id()));
+        let _ = std::fs::remove_file(&xml_output_file);
+
+        run_external_process("junit_xml_output")
+            .env("GOOGLETEST_XML_OUTPUT_FILE", &xml_output_file)
+            .output()?;
+        let xml_output = std::fs::read_to_string(&xml_output_file)?;
+        std::fs::remove_file(&xml_output_file)?;
+
+        verify_that!(
+            xml_output,
+            all!(
+                starts_with(indoc! {r#"
+                    <?xml version="1.0" encoding="UTF-8"?>
+                    <testsuites>
+                      <testsuite name="junit_xml_output" tests="3" failures="2" errors="0""#}),
+                contains_substring(r#"<testcase name="tests::passes" classname="junit_xml_output""#),
+                contains_substring(indoc! {r#"
+                    <failure message="Value of: 2">Value of: 2
+                    Expected: is equal to 3
+                    Actual: 2,
+                      which isn't equal to 3
+                      at integration_tests/src/junit_xml_output.rs:29:9</failure>"#}),
+                contains_substring(indoc! {r#"
+                    <failure message="Value of: &quot;&lt;value&gt;&quot;">Value of: "&lt;value&gt;"
+                    Expected: is equal to "other value""#}),
+                contains_substring(indoc! {r#"
+                    <testcase name="tests::panics" classname="junit_xml_output""#}),
+                contains_substring("The test panicked."),
+                ends_with("  </testsuite>\n</testsuites>\n"),
+            )
+        )
+    }
+
+    #[test]
+    fn junit_xml_output_replaces_test_suite_of_previous_run() -> Result<()> {
+        let xml_output_file =
+            std::env::temp_dir().join(format!("junit_xml_rerun_{}.xml", std::process::id()));
+        let _ = std::fs::remove_file(&xml_output_file);
+
+        for _ in 0..2 {
+            run_external_process("junit_xml_output")
+                .env("GOOGLETEST_XML_OUTPUT_FILE", &xml_output_file)
+                .output()?;
+        }
+        let xml_output = std::fs::read_to_string(&xml_output_file)?;
+        std::fs::remove_file(&xml_output_file)?;
+
+        verify_that!(xml_output.matches("<testsuite ").count(), eq(1))
+    }
+
+    #[test]
+    fn json_output_contains_record_of_each_failure() -> Result<()> {
+        let json_output_file =
+            std::env::temp_dir().join(format!("json_output_{}.jsonl", std::process::id()));
+        let _ = std::fs::remove_file(&json_output_file);
+
+        run_external_process("json_output")
+            .env("GOOGLETEST_JSON_OUTPUT_FILE", &json_output_file)
+            .output()?;
+        let json_output = std::fs::read_to_string(&json_output_file)?;
+        std::fs::remove_file(&json_output_file)?;
+
+        verify_that!(
+            json_output.lines().collect::<Vec<_>>(),
+            unordered_elements_are![
+                all!(
+                    starts_with(concat!(
+                        r#"{"test":"tests::fails_with_matcher","actual_expression":"value","#,
+                        r#""actual_value":"2","expected":"is equal to 3","#,
+                        r#""explanation":"which isn't equal to 3","#,
+                        r#""file":"integration_tests/src/json_output.rs","line":29,"column":9,"#,
+                    )),
+                    contains_substring(r#""message":"Value of: value\nExpected: is equal to 3"#),
+                ),
+                all!(
+                    starts_with(concat!(
+                        r#"{"test":"tests::fails_with_fail_macro","actual_expression":null,"#,
+                        r#""actual_value":null,"expected":null,"explanation":null,"#,
+                        r#""file":"integration_tests/src/json_output.rs","line":34,"column":9,"#,
+                    )),
+                    contains_substring(r#""message":"Something \"unexpected\" happened\n"#),
+                ),
+                starts_with(concat!(
+                    r#"{"test":"tests::fails_with_predicate","actual_expression":"is_odd(4)","#,
+                    r#""actual_value":null,"expected":null,"explanation":null,"#,
+                    r#""file":"integration_tests/src/json_output.rs","line":42,"column":9,"#,
+                )),
+            ]
+        )
+    }
+
     #[::core::prelude::v1::test]
     #[should_panic]
     fn should_panic_when_expect_that_runs_without_attribute_macro() {
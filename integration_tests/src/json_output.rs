@@ -0,0 +1,44 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+fn main() {}
+
+#[deny(warnings)]
+#[cfg(test)]
+mod tests {
+    use googletest::prelude::*;
+
+    #[googletest::test]
+    fn passes() {
+        expect_that!(1, eq(1));
+    }
+
+    #[googletest::test]
+    fn fails_with_matcher() {
+        let value = 2;
+        expect_that!(value, eq(3));
+    }
+
+    #[googletest::test]
+    fn fails_with_fail_macro() -> Result<()> {
+        fail!("Something \"unexpected\" happened")
+    }
+
+    #[googletest::test]
+    fn fails_with_predicate() -> Result<()> {
+        fn is_odd(value: i32) -> bool {
+            value % 2 == 1
+        }
+        verify_pred!(is_odd(4))
+    }
+}
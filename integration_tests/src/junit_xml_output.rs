@@ -0,0 +1,37 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+fn main() {}
+
+#[deny(warnings)]
+#[cfg(test)]
+mod tests {
+    use googletest::prelude::*;
+
+    #[googletest::test]
+    fn passes() {
+        expect_that!(1, eq(1));
+    }
+
+    #[googletest::test]
+    fn fails_with_non_fatal_and_fatal_failures() -> Result<()> {
+        expect_that!(2, eq(3));
+        verify_that!("<value>", eq("other value"))
+    }
+
+    #[googletest::test]
+    fn panics() {
+        assert_that!(4, eq(5));
+    }
+}
@@ -0,0 +1,35 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+fn main() {}
+
+#[deny(warnings)]
+#[cfg(test)]
+mod tests {
+    use googletest::prelude::*;
+
+    fn checked_divide(dividend: u32, divisor: u32) -> u32 {
+        dividend.checked_div(divisor).expect("attempt to divide by zero")
+    }
+
+    #[googletest::test]
+    fn does_not_panic() -> Result<()> {
+        verify_panics!(checked_divide(1, 1), contains_substring("divide by zero"))
+    }
+
+    #[googletest::test]
+    fn panics_with_other_message() {
+        expect_panic!(checked_divide(1, 0), contains_substring("overflow"));
+        expect_that!(1, eq(2));
+    }
+}
@@ -0,0 +1,38 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+fn main() {}
+
+#[deny(warnings)]
+#[cfg(test)]
+mod tests {
+    use googletest::prelude::*;
+
+    #[googletest::test(values = [2, 3, 4])]
+    fn value_is_even(value: i32) {
+        expect_that!(value % 2, eq(0));
+    }
+
+    #[derive(Debug)]
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    #[googletest::test(values = [Point { x: 1, y: 2 }, Point { x: -1, y: 2 }])]
+    fn point_is_in_first_quadrant(point: Point) -> Result<()> {
+        verify_that!(point.x, gt(0))?;
+        verify_that!(point.y, gt(0))
+    }
+}
@@ -0,0 +1,47 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+fn main() {}
+
+#[deny(warnings)]
+#[cfg(test)]
+mod tests {
+    use googletest::prelude::*;
+
+    #[googletest::test]
+    fn failure_in_loop_has_trace() {
+        for i in 1..=3 {
+            scoped_trace!("iteration {i}");
+            expect_that!(i, lt(3));
+        }
+        expect_that!("After the loop", eq("Outside of any trace"));
+    }
+
+    #[googletest::test]
+    fn failure_in_nested_scopes_has_all_traces() {
+        scoped_trace!("outer scope");
+        check_value(2);
+    }
+
+    fn check_value(value: i32) {
+        scoped_trace!("checking value {}", value);
+        expect_that!(value, eq(3));
+    }
+
+    #[googletest::test]
+    fn fatal_failure_has_trace() -> Result<()> {
+        scoped_trace!("fatal scope");
+        verify_that!(3, eq(4))
+    }
+}
@@ -0,0 +1,121 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+fn main() {}
+
+#[deny(warnings)]
+#[cfg(test)]
+mod tests {
+    use googletest::prelude::*;
+
+    struct FailingTearDown;
+
+    impl Fixture for FailingTearDown {
+        fn set_up() -> Result<Self> {
+            Ok(Self)
+        }
+
+        fn tear_down(self) -> Result<()> {
+            fail!("Tear-down failed")
+        }
+    }
+
+    struct FailingSetUp;
+
+    impl Fixture for FailingSetUp {
+        fn set_up() -> Result<Self> {
+            fail!("Set-up failed")?;
+            Ok(Self)
+        }
+
+        fn tear_down(self) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    struct FirstFixture;
+
+    impl Fixture for FirstFixture {
+        fn set_up() -> Result<Self> {
+            println!("Setting up FirstFixture");
+            Ok(Self)
+        }
+
+        fn tear_down(self) -> Result<()> {
+            println!("Tearing down FirstFixture");
+            Ok(())
+        }
+    }
+
+    struct SecondFixture;
+
+    impl Fixture for SecondFixture {
+        fn set_up() -> Result<Self> {
+            println!("Setting up SecondFixture");
+            Ok(Self)
+        }
+
+        fn tear_down(self) -> Result<()> {
+            println!("Tearing down SecondFixture");
+            Ok(())
+        }
+    }
+
+    struct PanicObserver;
+
+    impl Fixture for PanicObserver {
+        fn set_up() -> Result<Self> {
+            Ok(Self)
+        }
+
+        fn tear_down(self) -> Result<()> {
+            println!("Tearing down while panicking: {}", std::thread::panicking());
+            Ok(())
+        }
+    }
+
+    #[googletest::test]
+    fn tear_down_failure_is_recorded_with_body_failure(_fixture: &mut FailingTearDown) {
+        expect_that!(2, eq(3));
+    }
+
+    #[googletest::test]
+    fn tear_down_failure_fails_passing_test(_fixture: &FailingTearDown) -> Result<()> {
+        verify_that!(2, eq(2))
+    }
+
+    #[googletest::test]
+    fn tear_down_runs_after_fatal_failure(_fixture: &mut FailingTearDown) -> Result<()> {
+        verify_that!(3, eq(4))
+    }
+
+    #[googletest::test]
+    fn tear_down_runs_after_panic(_fixture: &mut PanicObserver) {
+        panic!("Body panicked");
+    }
+
+    #[googletest::test]
+    fn body_does_not_run_when_set_up_fails(
+        _first: &mut FirstFixture,
+        _failing: &mut FailingSetUp,
+        _second: &mut SecondFixture,
+    ) {
+        expect_that!("Body ran", eq("Body did not run"));
+    }
+
+    #[googletest::test]
+    fn fixtures_are_torn_down_in_reverse_order(_first: &mut FirstFixture, _second: &SecondFixture) {
+        fail!("Body of fixtures_are_torn_down_in_reverse_order").and_log_failure();
+    }
+}
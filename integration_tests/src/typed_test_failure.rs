@@ -0,0 +1,63 @@
+// Copyright 2023 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+fn main() {}
+
+#[deny(warnings)]
+#[cfg(test)]
+mod tests {
+    use googletest::prelude::*;
+
+    trait Storage: Default {
+        fn put(&mut self, value: u32);
+        fn get(&self) -> Option<u32>;
+    }
+
+    #[derive(Default)]
+    struct WorkingStorage(Option<u32>);
+
+    impl Storage for WorkingStorage {
+        fn put(&mut self, value: u32) {
+            self.0 = Some(value);
+        }
+
+        fn get(&self) -> Option<u32> {
+            self.0
+        }
+    }
+
+    #[derive(Default)]
+    struct ForgetfulStorage;
+
+    impl Storage for ForgetfulStorage {
+        fn put(&mut self, _: u32) {}
+
+        fn get(&self) -> Option<u32> {
+            None
+        }
+    }
+
+    #[googletest::test(types = [WorkingStorage, ForgetfulStorage])]
+    fn storage_returns_stored_value<S: Storage>() {
+        let mut storage = S::default();
+        storage.put(1);
+        expect_that!(storage.get(), some(eq(1)));
+    }
+
+    #[googletest::test(types = [WorkingStorage])]
+    #[allow(unused_variables)]
+    fn attributes_apply_to_generic_test_function<S: Storage>() {
+        let unused_storage = S::default();
+    }
+}
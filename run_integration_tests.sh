@@ -29,22 +29,31 @@ INTEGRATION_TEST_BINARIES=(
   "assertion_failures_with_short_structured_actual_values"
   "async_test_with_expect_that"
   "custom_error_message"
+  "death_test_failure"
   "expect_pred_failure"
   "expect_that_failure"
   "failure_due_to_fail_macro"
   "failure_due_to_fail_macro_with_empty_message"
   "failure_due_to_fail_macro_with_format_arguments"
   "failure_due_to_returned_error"
+  "failure_in_spawned_thread"
   "fatal_and_non_fatal_failure"
   "first_failure_aborts"
   "google_test_with_rstest"
+  "json_output"
+  "junit_xml_output"
   "non_fatal_failure_in_subroutine"
+  "panic_assertion_failure"
+  "parameterized_test_failure"
+  "scoped_trace_failure"
   "simple_assertion_failure"
   "simple_assertion_failure_with_assert_that"
   "test_returning_anyhow_error"
+  "test_with_fixtures"
   "two_expect_pred_failures"
   "two_expect_that_failures"
   "two_non_fatal_failures"
+  "typed_test_failure"
   "verify_predicate_with_failure"
   "verify_predicate_with_failure_as_method_in_submodule"
 )